@@ -0,0 +1,8 @@
+use bootcamp_core::snapshot::assert_snapshot;
+
+#[test]
+fn output_matches_snapshot() {
+    let mut out = Vec::new();
+    error_handling::run(&mut out).unwrap();
+    assert_snapshot(env!("CARGO_MANIFEST_DIR"), "output", &String::from_utf8(out).unwrap());
+}
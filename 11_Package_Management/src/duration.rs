@@ -0,0 +1,264 @@
+use chrono::Duration; //chrono::Duration is an alias for chrono::TimeDelta
+use std::fmt;
+
+const SECS_PER_MINUTE: i64 = 60;
+const SECS_PER_HOUR: i64 = 60 * SECS_PER_MINUTE;
+const SECS_PER_DAY: i64 = 24 * SECS_PER_HOUR;
+const SECS_PER_WEEK: i64 = 7 * SECS_PER_DAY;
+
+#[derive(Debug, PartialEq)]
+pub enum DurationParseError {
+    Empty,
+    InvalidNumber(String),
+    MissingUnit(String),
+    UnknownUnit(String),
+    Overflow,
+    InvalidIso(String),
+}
+
+impl fmt::Display for DurationParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DurationParseError::Empty => write!(f, "duration string is empty"),
+            DurationParseError::InvalidNumber(n) => write!(f, "invalid number: {}", n),
+            DurationParseError::MissingUnit(n) => write!(f, "missing unit after {}", n),
+            DurationParseError::UnknownUnit(u) => write!(f, "unknown unit: {}", u),
+            DurationParseError::Overflow => write!(f, "duration is too large"),
+            DurationParseError::InvalidIso(s) => write!(f, "invalid ISO 8601 duration: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for DurationParseError {}
+
+//Splits a duration into (days, hours, minutes, seconds), all non-negative. The sign is handled by the caller.
+fn split(d: Duration) -> (i64, i64, i64, i64) {
+    let secs = d.num_seconds().abs();
+    (
+        secs / SECS_PER_DAY,
+        secs % SECS_PER_DAY / SECS_PER_HOUR,
+        secs % SECS_PER_HOUR / SECS_PER_MINUTE,
+        secs % SECS_PER_MINUTE,
+    )
+}
+
+//Short human format, zero units are skipped: 3h 5m, 2d 4h, 45s
+pub fn format_duration(d: Duration) -> String {
+    let (days, hours, minutes, seconds) = split(d);
+    let mut parts = Vec::new();
+    for (value, unit) in [(days, "d"), (hours, "h"), (minutes, "m"), (seconds, "s")] {
+        if value != 0 {
+            parts.push(format!("{}{}", value, unit));
+        }
+    }
+    if parts.is_empty() {
+        return String::from("0s");
+    }
+    let sign = if d < Duration::zero() { "-" } else { "" };
+    format!("{}{}", sign, parts.join(" "))
+}
+
+//Relative format using only the largest unit: "in 2 days", "5 minutes ago", "just now"
+//A positive duration points to the future, a negative one to the past.
+pub fn format_relative(d: Duration) -> String {
+    let secs = d.num_seconds().abs();
+    let (value, unit) = if secs >= SECS_PER_DAY {
+        (secs / SECS_PER_DAY, "day")
+    } else if secs >= SECS_PER_HOUR {
+        (secs / SECS_PER_HOUR, "hour")
+    } else if secs >= SECS_PER_MINUTE {
+        (secs / SECS_PER_MINUTE, "minute")
+    } else if secs > 0 {
+        (secs, "second")
+    } else {
+        return String::from("just now");
+    };
+    let plural = if value == 1 { "" } else { "s" };
+    if d > Duration::zero() {
+        format!("in {} {}{}", value, unit, plural)
+    } else {
+        format!("{} {}{} ago", value, unit, plural)
+    }
+}
+
+fn unit_seconds(unit: &str) -> Option<i64> {
+    match unit {
+        "w" => Some(SECS_PER_WEEK),
+        "d" => Some(SECS_PER_DAY),
+        "h" => Some(SECS_PER_HOUR),
+        "m" | "min" => Some(SECS_PER_MINUTE),
+        "s" => Some(1),
+        _ => None,
+    }
+}
+
+//Adds `value * unit_secs` seconds to `total` without overflowing
+fn add_seconds(total: i64, value: i64, unit_secs: i64) -> Result<i64, DurationParseError> {
+    value
+        .checked_mul(unit_secs)
+        .and_then(|secs| total.checked_add(secs))
+        .ok_or(DurationParseError::Overflow)
+}
+
+fn seconds_to_duration(secs: i64) -> Result<Duration, DurationParseError> {
+    Duration::try_seconds(secs).ok_or(DurationParseError::Overflow)
+}
+
+//Parses strings like "1h30m", "90s", "2d 4h" or "1w" back into a Duration
+pub fn parse_duration(s: &str) -> Result<Duration, DurationParseError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(DurationParseError::Empty);
+    }
+
+    let mut total: i64 = 0;
+    let mut chars = s.chars().peekable();
+    while chars.peek().is_some() {
+        let mut number = String::new();
+        while let Some(c) = chars.peek().copied().filter(|c| c.is_ascii_digit()) {
+            number.push(c);
+            chars.next();
+        }
+        let mut unit = String::new();
+        while let Some(c) = chars.peek().copied().filter(|c| c.is_ascii_alphabetic()) {
+            unit.push(c);
+            chars.next();
+        }
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+        if number.is_empty() {
+            //either a unit without a number ("h") or a character we don't understand ("1h-")
+            let rest: String = if unit.is_empty() { chars.collect() } else { unit };
+            return Err(DurationParseError::InvalidNumber(rest));
+        }
+        if unit.is_empty() {
+            return Err(DurationParseError::MissingUnit(number));
+        }
+        let value: i64 = number.parse().map_err(|_| DurationParseError::Overflow)?;
+        let unit_secs = unit_seconds(&unit).ok_or(DurationParseError::UnknownUnit(unit))?;
+        total = add_seconds(total, value, unit_secs)?;
+    }
+    seconds_to_duration(total)
+}
+
+//ISO 8601 duration: PT1H30M, P2DT3H, PT0S. Sub-second precision is written as a decimal fraction of seconds.
+pub fn to_iso8601(d: Duration) -> String {
+    let (days, hours, minutes, seconds) = split(d);
+    let nanos = d.subsec_nanos().abs();
+
+    let mut out = String::new();
+    if d < Duration::zero() {
+        out.push('-');
+    }
+    out.push('P');
+    if days != 0 {
+        out.push_str(&format!("{}D", days));
+    }
+    if hours != 0 || minutes != 0 || seconds != 0 || nanos != 0 || days == 0 {
+        out.push('T');
+        if hours != 0 {
+            out.push_str(&format!("{}H", hours));
+        }
+        if minutes != 0 {
+            out.push_str(&format!("{}M", minutes));
+        }
+        if nanos != 0 {
+            let fraction = format!("{:09}", nanos);
+            out.push_str(&format!("{}.{}S", seconds, fraction.trim_end_matches('0')));
+        } else if seconds != 0 || (days == 0 && hours == 0 && minutes == 0) {
+            out.push_str(&format!("{}S", seconds));
+        }
+    }
+    out
+}
+
+//Parses the day/time subset of ISO 8601 durations (W, D, H, M, S). Years and months are rejected
+//because their length depends on the calendar, so they can't become a fixed Duration.
+pub fn parse_iso8601(s: &str) -> Result<Duration, DurationParseError> {
+    let invalid = || DurationParseError::InvalidIso(s.to_string());
+
+    let (negative, rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let rest = rest.strip_prefix('P').ok_or_else(invalid)?;
+    let (date_part, time_part) = match rest.split_once('T') {
+        Some((date, time)) if !time.is_empty() => (date, Some(time)),
+        Some(_) => return Err(invalid()),
+        None => (rest, None),
+    };
+    if date_part.is_empty() && time_part.is_none() {
+        return Err(invalid());
+    }
+
+    let mut total: i64 = 0;
+    let mut nanos: i64 = 0;
+    for (designator, value) in iso_components(date_part, "WD").ok_or_else(invalid)? {
+        let unit_secs = match designator {
+            'W' => SECS_PER_WEEK,
+            'D' => SECS_PER_DAY,
+            _ => return Err(invalid()),
+        };
+        let value = parse_digits(value).ok_or_else(invalid)?;
+        total = add_seconds(total, value, unit_secs)?;
+    }
+    for (designator, value) in iso_components(time_part.unwrap_or(""), "HMS").ok_or_else(invalid)? {
+        let (whole, fraction) = match value.split_once('.') {
+            //only seconds can have a fraction, and it needs at least one digit ("PT1.S" is not valid)
+            Some((whole, fraction)) if designator == 'S' && !fraction.is_empty() => (whole, fraction),
+            Some(_) => return Err(invalid()),
+            None => (value, ""),
+        };
+        let unit_secs = match designator {
+            'H' => SECS_PER_HOUR,
+            'M' => SECS_PER_MINUTE,
+            'S' => 1,
+            _ => return Err(invalid()),
+        };
+        let whole = parse_digits(whole).ok_or_else(invalid)?;
+        total = add_seconds(total, whole, unit_secs)?;
+        if !fraction.is_empty() {
+            if fraction.len() > 9 {
+                return Err(invalid());
+            }
+            nanos = parse_digits(&format!("{:0<9}", fraction)).ok_or_else(invalid)?;
+        }
+    }
+
+    let d = seconds_to_duration(total)?
+        .checked_add(&Duration::nanoseconds(nanos))
+        .ok_or(DurationParseError::Overflow)?;
+    Ok(if negative { -d } else { d })
+}
+
+//Like str::parse but rejects signs, so "PT-5M" is not accepted
+fn parse_digits(s: &str) -> Option<i64> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+//Splits "1H30M" into [('H', "1"), ('M', "30")]. Returns None for a trailing number without a designator,
+//or when the designators aren't in `order` ("PT30M1H" and "PT1S1S" are not valid ISO 8601).
+fn iso_components<'a>(s: &'a str, order: &str) -> Option<Vec<(char, &'a str)>> {
+    let mut components = Vec::new();
+    let mut start = 0;
+    let mut allowed = order;
+    for (i, c) in s.char_indices() {
+        if c.is_ascii_alphabetic() {
+            let value = &s[start..i];
+            if value.is_empty() {
+                return None;
+            }
+            //each designator at most once, and only ones that come after the previous one
+            allowed = &allowed[allowed.find(c)? + 1..];
+            components.push((c, value));
+            start = i + 1;
+        }
+    }
+    if start != s.len() {
+        return None;
+    }
+    Some(components)
+}
@@ -0,0 +1,83 @@
+use bootcamp_core::clock::ManualClock;
+use chrono::{Duration, Local, Utc}; //installed via `cargo add chrono`
+
+pub mod duration;
+pub mod storage;
+pub mod timer;
+
+use duration::{format_duration, format_relative, parse_duration, parse_iso8601, to_iso8601};
+use std::io::{self, Write};
+use storage::{TimeRecord, TimeStore};
+use timer::{Stopwatch, print_laps};
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    let now = Local::now();
+    writeln!(out, "Current time in Local: {}", now)?;
+
+    let now_utc = Utc::now();
+    writeln!(out, "Current time in UTC: {}", now_utc)?;
+
+    //Instead of printing raw DateTime values, we can describe durations the way a human would
+    let elapsed = Duration::hours(3) + Duration::minutes(5);
+    writeln!(out, "Elapsed: {}", format_duration(elapsed))?; // Output: 3h 5m
+    writeln!(out, "Deadline: {}", format_relative(Duration::days(2)))?; // Output: in 2 days
+    writeln!(out, "Last login: {}", format_relative(-Duration::minutes(5)))?; // Output: 5 minutes ago
+
+    match parse_duration("1h30m") {
+        Ok(d) => writeln!(out, "1h30m as ISO 8601: {}", to_iso8601(d))?, // Output: PT1H30M
+        Err(e) => eprintln!("Error parsing duration: {}", e),
+    }
+
+    match parse_iso8601("PT90S") {
+        Ok(d) => writeln!(out, "PT90S is {}", format_duration(d))?, // Output: 1m 30s
+        Err(e) => eprintln!("Error parsing duration: {}", e),
+    }
+
+    //Storing time: save timestamps to a file and read them back by time range
+    //a file per process, so every run starts with an empty store
+    let path = std::env::temp_dir().join(format!("package_management_times_{}.log", std::process::id()));
+    let store = TimeStore::open(&path);
+    let record = TimeRecord {
+        label: String::from("app started"),
+        at: now_utc,
+    };
+    if let Err(e) = store.append(&record) {
+        eprintln!("Error saving time: {}", e);
+    }
+    match store.between(now_utc - Duration::hours(1), now_utc + Duration::seconds(1)) {
+        Ok(records) => {
+            for r in records {
+                writeln!(out, "{} ({})", r.label, format_relative(r.at - Utc::now()))?;
+            }
+        }
+        Err(e) => eprintln!("Error loading times: {}", e),
+    }
+    let _ = std::fs::remove_file(&path); //nothing to clean up if saving failed
+
+    //A ManualClock only moves when we tell it to, so the lap times below are always the same
+    let clock = ManualClock::new();
+    let mut stopwatch = Stopwatch::with_clock(&clock);
+    stopwatch.start();
+    for millis in [1200, 950, 1430] {
+        clock.advance(std::time::Duration::from_millis(millis));
+        stopwatch.lap();
+    }
+    stopwatch.stop();
+    print_laps(stopwatch.laps(), out)?;
+
+    writeln!(out, "Hello, world!")?;
+    Ok(())
+}
+
+pub struct PackageManagement;
+
+impl bootcamp_core::Lesson for PackageManagement {
+    fn dir(&self) -> &'static str {
+        "11_Package_Management"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
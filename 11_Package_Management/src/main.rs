@@ -1,13 +1,62 @@
-use chrono::{Local,Utc}; //installed via `cargo add chrono`
-
+use package_management::duration::parse_duration;
+use package_management::timer::{self, Countdown, Stopwatch, print_laps};
+use std::io::{self, BufRead};
 
 fn main() {
+    //`cargo run -- stopwatch` or `cargo run -- countdown 10s` to use the timers from the terminal
+    let args = bootcamp_core::input::args();
+    match args.first().map(|s| s.as_str()) {
+        Some("stopwatch") => run_stopwatch(),
+        Some("countdown") => run_countdown(args.get(1).map_or("10s", |s| s.as_str())),
+        _ => package_management::run(&mut io::stdout()).unwrap(),
+    }
+}
+
+//Press Enter to record a lap, type `r` to reset and `q` to stop and print the splits
+fn run_stopwatch() {
+    let mut stopwatch = Stopwatch::new();
+    stopwatch.start();
+    println!("Stopwatch running. Enter = lap, r = reset, q = quit");
 
-    let now = Local::now();
-    println!("Current time in Local: {}", now);
+    for line in io::stdin().lock().lines() {
+        let Ok(line) = line else { break };
+        match line.trim() {
+            "q" => break,
+            "r" => {
+                stopwatch.reset();
+                stopwatch.start();
+                println!("Reset");
+            }
+            _ => {
+                if let Some(lap) = stopwatch.lap() {
+                    println!("Lap {}: {}", lap.number, timer::format_split(lap.split));
+                }
+            }
+        }
+    }
+
+    if stopwatch.is_running() {
+        stopwatch.stop();
+    }
+    print_laps(stopwatch.laps(), &mut io::stdout()).unwrap();
+    println!("Total: {}", timer::format_split(stopwatch.elapsed()));
+}
 
-    let now_utc = Utc::now();
-    println!("Current time in UTC: {}", now_utc);
+fn run_countdown(length: &str) {
+    let length = match parse_duration(length) {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!("Error parsing duration: {}", e);
+            return;
+        }
+    };
+    let Ok(length) = length.to_std() else {
+        eprintln!("Countdown length cannot be negative");
+        return;
+    };
 
-    println!("Hello, world!");
+    let mut countdown = Countdown::new(length)
+        .on_tick(|remaining| println!("{} left", timer::format_split(remaining)))
+        .on_finish(|| println!("Time's up!"));
+    countdown.run(std::time::Duration::from_secs(1));
 }
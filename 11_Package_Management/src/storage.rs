@@ -0,0 +1,115 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+//A single saved timestamp with a label describing what happened at that time
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeRecord {
+    pub label: String,
+    pub at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+pub enum StoreError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+    InvalidLabel(String),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StoreError::Io(e) => write!(f, "I/O error: {}", e),
+            StoreError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            StoreError::InvalidLabel(label) => write!(f, "label cannot contain a line break: {:?}", label),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+impl From<io::Error> for StoreError {
+    fn from(e: io::Error) -> Self {
+        StoreError::Io(e)
+    }
+}
+
+//Append-only file store. Every record is one line: `<RFC 3339 timestamp>\t<label>`.
+//Timestamps are always written in UTC with nanosecond precision, so reading them back
+//gives exactly the same DateTime<Utc> that was saved, no precision or timezone is lost.
+pub struct TimeStore {
+    path: PathBuf,
+}
+
+impl TimeStore {
+    pub fn open(path: impl AsRef<Path>) -> TimeStore {
+        TimeStore {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn append(&self, record: &TimeRecord) -> Result<(), StoreError> {
+        if record.label.contains(['\n', '\r']) {
+            return Err(StoreError::InvalidLabel(record.label.clone()));
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(
+            file,
+            "{}\t{}",
+            record.at.to_rfc3339_opts(SecondsFormat::Nanos, true),
+            record.label
+        )?;
+        Ok(())
+    }
+
+    //Loads every record in the order it was written. A missing file is just an empty store.
+    pub fn load_all(&self) -> Result<Vec<TimeRecord>, StoreError> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(StoreError::Io(e)),
+        };
+
+        let mut records = Vec::new();
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+            records.push(parse_line(&line).map_err(|message| StoreError::Parse {
+                line: index + 1,
+                message,
+            })?);
+        }
+        Ok(records)
+    }
+
+    //All records with `start <= at < end`, sorted by time
+    pub fn between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<TimeRecord>, StoreError> {
+        let mut records: Vec<TimeRecord> = self
+            .load_all()?
+            .into_iter()
+            .filter(|r| r.at >= start && r.at < end)
+            .collect();
+        records.sort_by_key(|r| r.at);
+        Ok(records)
+    }
+}
+
+fn parse_line(line: &str) -> Result<TimeRecord, String> {
+    let (timestamp, label) = line
+        .split_once('\t')
+        .ok_or_else(|| String::from("missing tab between timestamp and label"))?;
+    let at = DateTime::parse_from_rfc3339(timestamp)
+        .map_err(|e| format!("invalid timestamp {:?}: {}", timestamp, e))?;
+    Ok(TimeRecord {
+        label: label.to_string(),
+        at: at.with_timezone(&Utc),
+    })
+}
@@ -0,0 +1,189 @@
+use bootcamp_core::clock::{Clock, SystemClock};
+use bootcamp_core::output::Table;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lap {
+    pub number: usize,
+    pub split: Duration, //time since the previous lap
+    pub total: Duration, //time since the stopwatch was started
+}
+
+//The stopwatch and countdown only ask their bootcamp_core Clock for the time,
+//so tests can swap in a ManualClock and move time forward by hand.
+pub struct Stopwatch<C: Clock = SystemClock> {
+    clock: C,
+    running_since: Option<Instant>,
+    accumulated: Duration, //time from earlier start/stop cycles
+    laps: Vec<Lap>,
+}
+
+impl Stopwatch<SystemClock> {
+    pub fn new() -> Stopwatch<SystemClock> {
+        Stopwatch::with_clock(SystemClock)
+    }
+}
+
+impl Default for Stopwatch<SystemClock> {
+    fn default() -> Self {
+        Stopwatch::new()
+    }
+}
+
+impl<C: Clock> Stopwatch<C> {
+    pub fn with_clock(clock: C) -> Stopwatch<C> {
+        Stopwatch {
+            clock,
+            running_since: None,
+            accumulated: Duration::ZERO,
+            laps: Vec::new(),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    //Starting an already running stopwatch does nothing
+    pub fn start(&mut self) {
+        if self.running_since.is_none() {
+            self.running_since = Some(self.clock.now());
+        }
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(since) = self.running_since.take() {
+            self.accumulated += self.clock.now() - since;
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        match self.running_since {
+            Some(since) => self.accumulated + (self.clock.now() - since),
+            None => self.accumulated,
+        }
+    }
+
+    //Records a lap. Returns None if the stopwatch is not running.
+    pub fn lap(&mut self) -> Option<Lap> {
+        self.running_since?;
+        let total = self.elapsed();
+        let previous = self.laps.last().map_or(Duration::ZERO, |l| l.total);
+        let lap = Lap {
+            number: self.laps.len() + 1,
+            split: total - previous,
+            total,
+        };
+        self.laps.push(lap);
+        Some(lap)
+    }
+
+    pub fn laps(&self) -> &[Lap] {
+        &self.laps
+    }
+
+    //Stops the stopwatch and clears the elapsed time and all laps
+    pub fn reset(&mut self) {
+        self.running_since = None;
+        self.accumulated = Duration::ZERO;
+        self.laps.clear();
+    }
+}
+
+pub struct Countdown<'a, C: Clock = SystemClock> {
+    clock: C,
+    started: Instant,
+    length: Duration,
+    finished: bool,
+    on_tick: Option<Box<dyn FnMut(Duration) + 'a>>,
+    on_finish: Option<Box<dyn FnMut() + 'a>>,
+}
+
+impl<'a> Countdown<'a, SystemClock> {
+    pub fn new(length: Duration) -> Countdown<'a, SystemClock> {
+        Countdown::with_clock(SystemClock, length)
+    }
+}
+
+impl<'a, C: Clock> Countdown<'a, C> {
+    //The countdown starts as soon as it is created
+    pub fn with_clock(clock: C, length: Duration) -> Countdown<'a, C> {
+        let started = clock.now();
+        Countdown {
+            clock,
+            started,
+            length,
+            finished: false,
+            on_tick: None,
+            on_finish: None,
+        }
+    }
+
+    //Called on every poll while time is left, with the remaining time
+    pub fn on_tick(mut self, callback: impl FnMut(Duration) + 'a) -> Self {
+        self.on_tick = Some(Box::new(callback));
+        self
+    }
+
+    //Called exactly once, on the first poll after the countdown reaches zero
+    pub fn on_finish(mut self, callback: impl FnMut() + 'a) -> Self {
+        self.on_finish = Some(Box::new(callback));
+        self
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.length.saturating_sub(self.clock.now() - self.started)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    //Checks the clock and fires the callbacks. Returns true once the countdown is over.
+    pub fn poll(&mut self) -> bool {
+        if self.finished {
+            return true;
+        }
+        let remaining = self.remaining();
+        if remaining.is_zero() {
+            self.finished = true;
+            if let Some(callback) = self.on_finish.as_mut() {
+                callback();
+            }
+        } else if let Some(callback) = self.on_tick.as_mut() {
+            callback(remaining);
+        }
+        self.finished
+    }
+
+    //Blocks the current thread, polling every `interval` until the countdown is over
+    pub fn run(&mut self, interval: Duration) {
+        while !self.poll() {
+            std::thread::sleep(interval.min(self.remaining()));
+        }
+    }
+}
+
+//Formats a duration as mm:ss.mmm, or h:mm:ss.mmm once it goes past an hour
+pub fn format_split(d: Duration) -> String {
+    let secs = d.as_secs();
+    let millis = d.subsec_millis();
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}.{:03}", secs / 3600, secs % 3600 / 60, secs % 60, millis)
+    } else {
+        format!("{:02}:{:02}.{:03}", secs / 60, secs % 60, millis)
+    }
+}
+
+pub fn print_laps(laps: &[Lap], out: &mut impl Write) -> io::Result<()> {
+    let mut table = Table::new(&["Lap", "Split", "Total"]);
+    for lap in laps {
+        table.row(&[
+            lap.number.to_string(),
+            format_split(lap.split),
+            format_split(lap.total),
+        ]);
+    }
+    table.write_to(out)
+}
@@ -0,0 +1,138 @@
+use chrono::Duration;
+use package_management::duration::{
+    format_duration, format_relative, parse_duration, parse_iso8601, to_iso8601, DurationParseError,
+};
+use proptest::prelude::*;
+
+#[test]
+fn format_duration_skips_zero_units() {
+    assert_eq!(format_duration(Duration::hours(3) + Duration::minutes(5)), "3h 5m");
+    assert_eq!(format_duration(Duration::days(2) + Duration::hours(4)), "2d 4h");
+    assert_eq!(format_duration(Duration::seconds(45)), "45s");
+    assert_eq!(format_duration(Duration::seconds(90_061)), "1d 1h 1m 1s");
+    assert_eq!(format_duration(Duration::weeks(2)), "14d");
+}
+
+#[test]
+fn format_duration_zero_and_negative() {
+    assert_eq!(format_duration(Duration::zero()), "0s");
+    assert_eq!(format_duration(Duration::milliseconds(400)), "0s"); //less than a second shows as nothing
+    assert_eq!(format_duration(-Duration::seconds(90)), "-1m 30s");
+    assert_eq!(format_duration(-Duration::days(1)), "-1d");
+}
+
+#[test]
+fn format_relative_past_and_future() {
+    assert_eq!(format_relative(Duration::days(2)), "in 2 days");
+    assert_eq!(format_relative(Duration::hours(1)), "in 1 hour");
+    assert_eq!(format_relative(Duration::seconds(59)), "in 59 seconds");
+    assert_eq!(format_relative(-Duration::minutes(5)), "5 minutes ago");
+    assert_eq!(format_relative(-Duration::seconds(1)), "1 second ago");
+    //only the largest unit is shown, rounded down
+    assert_eq!(format_relative(-(Duration::days(1) + Duration::hours(23))), "1 day ago");
+}
+
+#[test]
+fn format_relative_now() {
+    assert_eq!(format_relative(Duration::zero()), "just now");
+    assert_eq!(format_relative(Duration::milliseconds(999)), "just now");
+    assert_eq!(format_relative(-Duration::milliseconds(999)), "just now");
+}
+
+#[test]
+fn parse_duration_accepts_combined_units() {
+    assert_eq!(parse_duration("1h30m"), Ok(Duration::minutes(90)));
+    assert_eq!(parse_duration("90s"), Ok(Duration::seconds(90)));
+    assert_eq!(parse_duration("2d 4h"), Ok(Duration::hours(52)));
+    assert_eq!(parse_duration("1w"), Ok(Duration::days(7)));
+    assert_eq!(parse_duration("10min"), Ok(Duration::minutes(10)));
+    assert_eq!(parse_duration("  5m 5m  "), Ok(Duration::minutes(10))); //repeats add up
+    assert_eq!(parse_duration("0s"), Ok(Duration::zero()));
+}
+
+#[test]
+fn parse_duration_errors() {
+    assert_eq!(parse_duration(""), Err(DurationParseError::Empty));
+    assert_eq!(parse_duration("   "), Err(DurationParseError::Empty));
+    assert_eq!(parse_duration("5x"), Err(DurationParseError::UnknownUnit(String::from("x"))));
+    assert_eq!(parse_duration("3hours"), Err(DurationParseError::UnknownUnit(String::from("hours"))));
+    assert_eq!(parse_duration("15"), Err(DurationParseError::MissingUnit(String::from("15"))));
+    assert_eq!(parse_duration("h"), Err(DurationParseError::InvalidNumber(String::from("h"))));
+    assert_eq!(parse_duration("1h-"), Err(DurationParseError::InvalidNumber(String::from("-"))));
+    assert_eq!(parse_duration("-5m"), Err(DurationParseError::InvalidNumber(String::from("-5m"))));
+}
+
+#[test]
+fn parse_duration_overflow() {
+    //too many digits for an i64
+    assert_eq!(parse_duration("99999999999999999999s"), Err(DurationParseError::Overflow));
+    //fits in an i64, but not once multiplied by the seconds in a week
+    assert_eq!(parse_duration("9223372036854775807w"), Err(DurationParseError::Overflow));
+    //fits as seconds, but chrono can't hold that many
+    assert_eq!(parse_duration("9223372036854775807s"), Err(DurationParseError::Overflow));
+    assert_eq!(
+        parse_duration("9223372036854775807s 1s"),
+        Err(DurationParseError::Overflow)
+    );
+}
+
+#[test]
+fn iso8601_format() {
+    assert_eq!(to_iso8601(Duration::minutes(90)), "PT1H30M");
+    assert_eq!(to_iso8601(Duration::zero()), "PT0S");
+    assert_eq!(to_iso8601(Duration::days(2) + Duration::hours(3)), "P2DT3H");
+    assert_eq!(to_iso8601(Duration::days(1)), "P1D");
+    assert_eq!(to_iso8601(Duration::milliseconds(1500)), "PT1.5S");
+    assert_eq!(to_iso8601(Duration::nanoseconds(1)), "PT0.000000001S");
+    assert_eq!(to_iso8601(-Duration::minutes(5)), "-PT5M");
+}
+
+#[test]
+fn iso8601_parse() {
+    assert_eq!(parse_iso8601("PT1H30M"), Ok(Duration::minutes(90)));
+    assert_eq!(parse_iso8601("PT90S"), Ok(Duration::seconds(90)));
+    assert_eq!(parse_iso8601("P1W"), Ok(Duration::weeks(1)));
+    assert_eq!(parse_iso8601("P2DT3H"), Ok(Duration::hours(51)));
+    assert_eq!(parse_iso8601("PT0.25S"), Ok(Duration::milliseconds(250)));
+    assert_eq!(parse_iso8601("-PT5M"), Ok(-Duration::minutes(5)));
+    assert_eq!(parse_iso8601("P1W2DT1H2M3S"), Ok(Duration::seconds(9 * 86400 + 3723)));
+}
+
+#[test]
+fn iso8601_errors() {
+    for bad in [
+        "", "1H", "P", "PT", "-P", "P1Y", "P1M", "P1H", "PT1D", "PT1", "PT-5M", "PTH", "PT1.5M",
+        "PT1.1234567891S", "PT1.S", "P1DT", "pt1h", "PT1S1S", "PT30M1H", "P1D1W", "P1D2D", "PT1H1M1H",
+    ] {
+        assert_eq!(
+            parse_iso8601(bad),
+            Err(DurationParseError::InvalidIso(bad.to_string())),
+            "{:?} should be rejected",
+            bad
+        );
+    }
+    assert_eq!(parse_iso8601("P99999999999999W"), Err(DurationParseError::Overflow));
+    //the whole seconds just fit, the fraction on top doesn't
+    assert_eq!(parse_iso8601("PT9223372036854775.9S"), Err(DurationParseError::Overflow));
+    assert_eq!(parse_iso8601("-PT9223372036854775.9S"), Err(DurationParseError::Overflow));
+    assert_eq!(
+        DurationParseError::InvalidIso(String::from("P1Y")).to_string(),
+        "invalid ISO 8601 duration: P1Y"
+    );
+}
+
+proptest! {
+    //Every duration chrono can hold comes back exactly, nanoseconds and sign included
+    #[test]
+    fn iso8601_round_trip(nanos in -1_000_000_000_000_000_000i64..1_000_000_000_000_000_000) {
+        let d = Duration::nanoseconds(nanos);
+        prop_assert_eq!(parse_iso8601(&to_iso8601(d)), Ok(d));
+    }
+
+    #[test]
+    fn format_duration_round_trips_through_parse(secs in 0i64..10_000_000_000) {
+        let d = Duration::seconds(secs);
+        let text = format_duration(d);
+        prop_assert_eq!(parse_duration(&text), Ok(d));
+    }
+}
@@ -0,0 +1,12 @@
+use bootcamp_core::snapshot::{assert_snapshot, redact_after};
+
+#[test]
+fn output_matches_snapshot() {
+    let mut out = Vec::new();
+    package_management::run(&mut out).unwrap();
+    let mut output = String::from_utf8(out).unwrap();
+    //the current time changes on every run
+    output = redact_after(&output, "Current time in Local: ");
+    output = redact_after(&output, "Current time in UTC: ");
+    assert_snapshot(env!("CARGO_MANIFEST_DIR"), "output", &output);
+}
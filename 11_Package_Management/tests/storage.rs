@@ -0,0 +1,132 @@
+use chrono::{DateTime, Duration, FixedOffset, TimeZone, Utc};
+use package_management::storage::{StoreError, TimeRecord, TimeStore};
+use std::path::PathBuf;
+
+//A file in the temp directory that is deleted again when the test ends, even if it fails
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str) -> TempFile {
+        let path = std::env::temp_dir().join(format!("package_management_{}_{}.log", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        TempFile(path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn record(label: &str, at: DateTime<Utc>) -> TimeRecord {
+    TimeRecord {
+        label: label.to_string(),
+        at,
+    }
+}
+
+#[test]
+fn nanoseconds_survive_the_round_trip() {
+    let file = TempFile::new("nanos");
+    let store = TimeStore::open(&file.0);
+    let base = Utc.with_ymd_and_hms(2024, 2, 29, 23, 59, 59).unwrap();
+    let saved = vec![
+        record("one nanosecond", base + Duration::nanoseconds(1)),
+        record("all nine digits", base + Duration::nanoseconds(123_456_789)),
+        record("whole second", base),
+        record("before 1970", Utc.with_ymd_and_hms(1969, 7, 20, 20, 17, 40).unwrap() + Duration::nanoseconds(999_999_999)),
+        record("now", Utc::now()),
+    ];
+    for r in &saved {
+        store.append(r).unwrap();
+    }
+    assert_eq!(store.load_all().unwrap(), saved);
+}
+
+#[test]
+fn other_timezones_come_back_as_the_same_instant() {
+    let file = TempFile::new("offsets");
+    let store = TimeStore::open(&file.0);
+    let india = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+    let in_india = india.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap() + Duration::nanoseconds(42);
+    let in_new_york = new_york.with_ymd_and_hms(2024, 3, 10, 1, 30, 0).unwrap();
+
+    store.append(&record("india", in_india.with_timezone(&Utc))).unwrap();
+    store.append(&record("new york", in_new_york.with_timezone(&Utc))).unwrap();
+    let loaded = store.load_all().unwrap();
+    assert_eq!(loaded[0].at, in_india);
+    assert_eq!(loaded[0].at.to_rfc3339(), "2024-03-10T06:30:00.000000042+00:00");
+    assert_eq!(loaded[1].at, in_new_york);
+
+    //a line written by hand with an offset is read as the same instant in UTC
+    std::fs::write(&file.0, "2024-03-10T12:00:00.5+05:30\thand written\n").unwrap();
+    let loaded = store.load_all().unwrap();
+    assert_eq!(loaded[0].at, Utc.with_ymd_and_hms(2024, 3, 10, 6, 30, 0).unwrap() + Duration::milliseconds(500));
+}
+
+#[test]
+fn between_includes_start_excludes_end_and_sorts() {
+    let file = TempFile::new("between");
+    let store = TimeStore::open(&file.0);
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let end = start + Duration::hours(1);
+    for (label, at) in [
+        ("late", end - Duration::nanoseconds(1)),
+        ("at end", end),
+        ("before", start - Duration::nanoseconds(1)),
+        ("at start", start),
+        ("middle", start + Duration::minutes(30)),
+    ] {
+        store.append(&record(label, at)).unwrap();
+    }
+    let labels: Vec<String> = store.between(start, end).unwrap().into_iter().map(|r| r.label).collect();
+    assert_eq!(labels, ["at start", "middle", "late"]);
+    assert!(store.between(end, start).unwrap().is_empty());
+}
+
+#[test]
+fn missing_file_is_an_empty_store() {
+    let file = TempFile::new("missing");
+    assert!(TimeStore::open(&file.0).load_all().unwrap().is_empty());
+}
+
+#[test]
+fn labels_keep_tabs_but_not_line_breaks() {
+    let file = TempFile::new("labels");
+    let store = TimeStore::open(&file.0);
+    let at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    store.append(&record("tab\tin the middle", at)).unwrap();
+    assert_eq!(store.load_all().unwrap()[0].label, "tab\tin the middle");
+
+    assert!(matches!(store.append(&record("two\nlines", at)), Err(StoreError::InvalidLabel(_))));
+    assert_eq!(store.load_all().unwrap().len(), 1);
+}
+
+#[test]
+fn corrupt_lines_report_their_line_number() {
+    let file = TempFile::new("corrupt");
+    let store = TimeStore::open(&file.0);
+    std::fs::write(&file.0, "2024-01-01T00:00:00Z\tok\n\nno tab here\n").unwrap();
+    match store.load_all() {
+        Err(StoreError::Parse { line, message }) => {
+            assert_eq!(line, 3);
+            assert_eq!(message, "missing tab between timestamp and label");
+        }
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+
+    std::fs::write(&file.0, "yesterday\tlabel\n").unwrap();
+    let err = store.load_all().unwrap_err();
+    assert!(err.to_string().starts_with("line 1: invalid timestamp \"yesterday\""), "{}", err);
+}
+
+#[test]
+fn io_errors() {
+    //a directory can't be read or appended to like a file
+    let store = TimeStore::open(std::env::temp_dir());
+    assert!(matches!(store.load_all(), Err(StoreError::Io(_))));
+    let at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    assert!(matches!(store.append(&record("x", at)), Err(StoreError::Io(_))));
+}
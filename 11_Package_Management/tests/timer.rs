@@ -0,0 +1,135 @@
+use bootcamp_core::clock::ManualClock;
+use package_management::timer::{format_split, print_laps, Countdown, Lap, Stopwatch};
+use std::cell::Cell;
+use std::time::Duration;
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test]
+fn laps_record_splits_and_totals() {
+    let clock = ManualClock::new();
+    let mut stopwatch = Stopwatch::with_clock(&clock);
+    assert_eq!(stopwatch.lap(), None); //not running yet
+
+    stopwatch.start();
+    for millis in [1200, 950, 1430] {
+        clock.advance(ms(millis));
+        stopwatch.lap();
+    }
+    let laps = stopwatch.laps();
+    assert_eq!(
+        laps,
+        [
+            Lap { number: 1, split: ms(1200), total: ms(1200) },
+            Lap { number: 2, split: ms(950), total: ms(2150) },
+            Lap { number: 3, split: ms(1430), total: ms(3580) },
+        ]
+    );
+    assert_eq!(laps.iter().map(|l| l.split).sum::<Duration>(), stopwatch.elapsed());
+}
+
+#[test]
+fn stopped_time_is_not_counted() {
+    let clock = ManualClock::new();
+    let mut stopwatch = Stopwatch::with_clock(&clock);
+    clock.advance(ms(500)); //before the first start
+    assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+
+    stopwatch.start();
+    clock.advance(ms(300));
+    stopwatch.stop();
+    assert!(!stopwatch.is_running());
+    clock.advance(ms(10_000));
+    assert_eq!(stopwatch.elapsed(), ms(300));
+
+    stopwatch.start();
+    stopwatch.start(); //already running, doesn't restart the current stretch
+    clock.advance(ms(200));
+    assert_eq!(stopwatch.elapsed(), ms(500));
+    stopwatch.stop();
+    stopwatch.stop();
+    assert_eq!(stopwatch.elapsed(), ms(500));
+
+    //a lap after a pause only counts the running time
+    stopwatch.start();
+    clock.advance(ms(100));
+    assert_eq!(stopwatch.lap().unwrap().total, ms(600));
+}
+
+#[test]
+fn reset_clears_everything() {
+    let clock = ManualClock::new();
+    let mut stopwatch = Stopwatch::with_clock(&clock);
+    stopwatch.start();
+    clock.advance(ms(700));
+    stopwatch.lap();
+    stopwatch.reset();
+    assert!(!stopwatch.is_running());
+    assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+    assert!(stopwatch.laps().is_empty());
+
+    stopwatch.start();
+    clock.advance(ms(40));
+    assert_eq!(stopwatch.lap().unwrap(), Lap { number: 1, split: ms(40), total: ms(40) });
+}
+
+#[test]
+fn countdown_finishes_exactly_once() {
+    let clock = ManualClock::new();
+    let finished = Cell::new(0);
+    let ticks = Cell::new(Vec::new());
+    let mut countdown = Countdown::with_clock(&clock, ms(1000))
+        .on_tick(|left| {
+            let mut seen = ticks.take();
+            seen.push(left);
+            ticks.set(seen);
+        })
+        .on_finish(|| finished.set(finished.get() + 1));
+
+    assert!(!countdown.poll());
+    clock.advance(ms(999));
+    assert_eq!(countdown.remaining(), ms(1));
+    assert!(!countdown.poll());
+    assert!(!countdown.is_finished());
+    assert_eq!(finished.get(), 0); //not a moment early
+
+    clock.advance(ms(1));
+    assert!(countdown.poll());
+    assert_eq!(finished.get(), 1);
+    clock.advance(ms(5000));
+    assert!(countdown.poll());
+    assert!(countdown.poll());
+    assert_eq!(finished.get(), 1);
+    assert!(countdown.is_finished());
+    assert_eq!(countdown.remaining(), Duration::ZERO);
+
+    drop(countdown);
+    assert_eq!(ticks.take(), [ms(1000), ms(1)]); //no ticks once it's over
+}
+
+#[test]
+fn countdown_can_jump_past_zero() {
+    let clock = ManualClock::new();
+    let finished = Cell::new(0);
+    let mut countdown = Countdown::with_clock(&clock, ms(50)).on_finish(|| finished.set(finished.get() + 1));
+    clock.advance(Duration::from_secs(60));
+    assert!(countdown.poll());
+    assert_eq!(finished.get(), 1);
+
+    //a zero length countdown is over on the first poll
+    let mut instant = Countdown::with_clock(&clock, Duration::ZERO);
+    assert!(instant.poll());
+}
+
+#[test]
+fn splits_format_as_minutes_or_hours() {
+    assert_eq!(format_split(ms(1200)), "00:01.200");
+    assert_eq!(format_split(ms(61_005)), "01:01.005");
+    assert_eq!(format_split(Duration::from_secs(3600 * 2 + 61)), "2:01:01.000");
+
+    let mut out = Vec::new();
+    print_laps(&[Lap { number: 1, split: ms(1200), total: ms(1200) }], &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "Lap |     Split |     Total\n----+-----------+----------\n  1 | 00:01.200 | 00:01.200\n");
+}
@@ -0,0 +1,381 @@
+use bootcamp_core::clock::{Clock, SystemClock};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+//A HashMap that never grows past `capacity`. When it's full the entry that was used least recently
+//is thrown out to make room.
+//
+//The map finds an entry in O(1), but "which entry is the oldest?" needs an order. That order is a
+//doubly linked list: every get/put moves the entry to the front, evictions take from the back.
+//Instead of pointers (which would need unsafe or Rc<RefCell>) the list nodes live in a Vec and
+//point at each other by index. The HashMap stores each key's index into that Vec.
+pub struct LruCache<K, V> {
+    map: HashMap<K, usize>,
+    nodes: Vec<Option<Node<K, V>>>, //None marks a slot that can be reused
+    free: Vec<usize>,
+    head: Option<usize>, //most recently used
+    tail: Option<usize>, //least recently used, evicted first
+    capacity: usize,
+    stats: CacheStats,
+}
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,   //entries pushed out because the cache was full
+    pub expirations: u64, //entries dropped because their time ran out (TtlCache only)
+}
+
+impl CacheStats {
+    //Fraction of lookups that found something, 0.0 when nothing was looked up yet
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 { 0.0 } else { self.hits as f64 / total as f64 }
+    }
+}
+
+//What both caches can do, so SharedCache can wrap either of them
+pub trait Cache<K, V> {
+    fn get(&mut self, key: &K) -> Option<&V>;
+    fn put(&mut self, key: K, value: V) -> Option<V>;
+    fn remove(&mut self, key: &K) -> Option<V>;
+    fn len(&self) -> usize;
+    fn stats(&self) -> CacheStats;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    pub fn new(capacity: usize) -> LruCache<K, V> {
+        assert!(capacity > 0, "an LruCache needs room for at least one entry");
+        LruCache {
+            map: HashMap::with_capacity(capacity),
+            nodes: Vec::with_capacity(capacity),
+            free: Vec::new(),
+            head: None,
+            tail: None,
+            capacity,
+            stats: CacheStats::default(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    //Looks at a value without counting as a use, so it doesn't change the eviction order or the stats
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.map.get(key).map(|&index| &self.node(index).value)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let index = self.touch(key)?;
+        Some(&mut self.node_mut(index).value)
+    }
+
+    //Entries from most to least recently used, i.e. the last one is evicted next
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut current = self.head;
+        std::iter::from_fn(move || {
+            let node = self.node(current?);
+            current = node.next;
+            Some((&node.key, &node.value))
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.nodes.clear();
+        self.free.clear();
+        self.head = None;
+        self.tail = None;
+    }
+
+    //Marks `key` as just used and returns its index, updating the hit/miss counters
+    fn touch(&mut self, key: &K) -> Option<usize> {
+        match self.map.get(key) {
+            Some(&index) => {
+                self.stats.hits += 1;
+                self.unlink(index);
+                self.push_front(index);
+                Some(index)
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    //Removes and returns the least recently used entry
+    fn pop_back(&mut self) -> Option<(K, V)> {
+        let index = self.tail?;
+        self.unlink(index);
+        let node = self.nodes[index].take().expect("list points at an empty slot");
+        self.free.push(index);
+        self.map.remove(&node.key);
+        Some((node.key, node.value))
+    }
+
+    fn node(&self, index: usize) -> &Node<K, V> {
+        self.nodes[index].as_ref().expect("list points at an empty slot")
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut Node<K, V> {
+        self.nodes[index].as_mut().expect("list points at an empty slot")
+    }
+
+    //Takes a node out of the list (it stays in its slot)
+    fn unlink(&mut self, index: usize) {
+        let (prev, next) = {
+            let node = self.node(index);
+            (node.prev, node.next)
+        };
+        match prev {
+            Some(p) => self.node_mut(p).next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(n) => self.node_mut(n).prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn push_front(&mut self, index: usize) {
+        let old_head = self.head;
+        {
+            let node = self.node_mut(index);
+            node.prev = None;
+            node.next = old_head;
+        }
+        match old_head {
+            Some(h) => self.node_mut(h).prev = Some(index),
+            None => self.tail = Some(index),
+        }
+        self.head = Some(index);
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> Cache<K, V> for LruCache<K, V> {
+    fn get(&mut self, key: &K) -> Option<&V> {
+        let index = self.touch(key)?;
+        Some(&self.node(index).value)
+    }
+
+    //Inserts or replaces a value and returns the old one. Evicts the least recently used entry if full.
+    fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&index) = self.map.get(&key) {
+            self.unlink(index);
+            self.push_front(index);
+            return Some(std::mem::replace(&mut self.node_mut(index).value, value));
+        }
+
+        if self.map.len() == self.capacity {
+            self.pop_back();
+            self.stats.evictions += 1;
+        }
+        let node = Node {
+            key: key.clone(),
+            value,
+            prev: None,
+            next: None,
+        };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = Some(node);
+                index
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        };
+        self.map.insert(key, index);
+        self.push_front(index);
+        None
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.map.remove(key)?;
+        self.unlink(index);
+        self.free.push(index);
+        self.nodes[index].take().map(|node| node.value)
+    }
+
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    fn stats(&self) -> CacheStats {
+        self.stats
+    }
+}
+
+//An LruCache whose entries also go stale: after `ttl` an entry counts as missing.
+//Expired entries are dropped when they are looked up, or all at once with `purge_expired`.
+//The time comes from a bootcamp_core Clock, tests use a ManualClock so they don't have to sleep.
+pub struct TtlCache<K, V, C: Clock = SystemClock> {
+    inner: LruCache<K, (V, Option<Instant>)>, //value and the moment it expires, None for never
+    ttl: Duration,
+    clock: C,
+}
+
+impl<K: Hash + Eq + Clone, V> TtlCache<K, V, SystemClock> {
+    pub fn new(capacity: usize, ttl: Duration) -> TtlCache<K, V, SystemClock> {
+        TtlCache::with_clock(capacity, ttl, SystemClock)
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, C: Clock> TtlCache<K, V, C> {
+    pub fn with_clock(capacity: usize, ttl: Duration, clock: C) -> TtlCache<K, V, C> {
+        TtlCache {
+            inner: LruCache::new(capacity),
+            ttl,
+            clock,
+        }
+    }
+
+    //Like put, but this entry lives for `ttl` instead of the cache's default
+    pub fn put_with_ttl(&mut self, key: K, value: V, ttl: Duration) -> Option<V> {
+        //a TTL so long the clock can't count that far (Duration::MAX, say) just never runs out
+        let expires = self.clock.now().checked_add(ttl);
+        self.inner.put(key, (value, expires)).map(|(old, _)| old)
+    }
+
+    //How long until `key` expires, None if it isn't cached (or already expired).
+    //Duration::MAX for an entry that never expires.
+    pub fn time_left(&self, key: &K) -> Option<Duration> {
+        match self.inner.peek(key)? {
+            (_, Some(expires)) => expires.checked_duration_since(self.clock.now()).filter(|d| !d.is_zero()),
+            (_, None) => Some(Duration::MAX),
+        }
+    }
+
+    //Drops every expired entry and returns how many there were
+    pub fn purge_expired(&mut self) -> usize {
+        let now = self.clock.now();
+        let expired: Vec<K> = self
+            .inner
+            .iter()
+            .filter(|(_, (_, expires))| expires.is_some_and(|e| e <= now))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &expired {
+            self.inner.remove(key);
+        }
+        self.inner.stats.expirations += expired.len() as u64;
+        expired.len()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, C: Clock> Cache<K, V> for TtlCache<K, V, C> {
+    fn get(&mut self, key: &K) -> Option<&V> {
+        let expired = matches!(self.inner.peek(key), Some((_, Some(expires))) if *expires <= self.clock.now());
+        if expired {
+            self.inner.remove(key);
+            self.inner.stats.expirations += 1;
+            self.inner.stats.misses += 1;
+            return None;
+        }
+        self.inner.get(key).map(|(value, _)| value)
+    }
+
+    fn put(&mut self, key: K, value: V) -> Option<V> {
+        self.put_with_ttl(key, value, self.ttl)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.inner.remove(key).map(|(value, _)| value)
+    }
+
+    //Counts expired entries that haven't been purged yet
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn stats(&self) -> CacheStats {
+        self.inner.stats
+    }
+}
+
+//Either cache behind a Mutex, shareable between threads (clone it, every clone is the same cache).
+//Values are cloned out because a reference can't outlive the lock.
+pub struct SharedCache<C> {
+    inner: Arc<Mutex<C>>,
+}
+
+impl<C> Clone for SharedCache<C> {
+    fn clone(&self) -> Self {
+        SharedCache {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<C> SharedCache<C> {
+    pub fn new(cache: C) -> SharedCache<C> {
+        SharedCache {
+            inner: Arc::new(Mutex::new(cache)),
+        }
+    }
+
+    //Runs `f` with the lock held, for anything the methods below don't cover
+    pub fn with_lock<R>(&self, f: impl FnOnce(&mut C) -> R) -> R {
+        //lock() only fails if another thread panicked mid-update, then the cache can't be trusted anyway
+        let mut cache = self.inner.lock().unwrap();
+        f(&mut cache)
+    }
+
+    pub fn get<K, V: Clone>(&self, key: &K) -> Option<V>
+    where
+        C: Cache<K, V>,
+    {
+        self.with_lock(|cache| cache.get(key).cloned())
+    }
+
+    pub fn put<K, V>(&self, key: K, value: V) -> Option<V>
+    where
+        C: Cache<K, V>,
+    {
+        self.with_lock(|cache| cache.put(key, value))
+    }
+
+    //Returns the cached value, or computes, caches and returns it. The lock is held while `compute`
+    //runs, so two threads asking for the same missing key don't both compute it.
+    pub fn get_or_insert_with<K, V: Clone>(&self, key: K, compute: impl FnOnce() -> V) -> V
+    where
+        C: Cache<K, V>,
+    {
+        self.with_lock(|cache| {
+            if let Some(value) = cache.get(&key) {
+                return value.clone();
+            }
+            let value = compute();
+            cache.put(key, value.clone());
+            value
+        })
+    }
+
+    pub fn stats<K, V>(&self) -> CacheStats
+    where
+        C: Cache<K, V>,
+    {
+        self.with_lock(|cache| cache.stats())
+    }
+}
@@ -0,0 +1,354 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+//Nodes are referred to by their position in the graph, which is cheap to copy and to use as an index
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub usize);
+
+//An adjacency list: for every node, a Vec of (neighbour, edge value). Node values of type N could be
+//names or cities, edge values of type E weights or distances.
+//Undirected graphs store every edge twice, once from each end.
+pub struct Graph<N, E> {
+    nodes: Vec<N>,
+    edges: Vec<Vec<(NodeId, E)>>,
+    directed: bool,
+}
+
+#[derive(Debug)]
+pub enum GraphError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+    Cycle(Vec<NodeId>), //the nodes on the cycle, first node repeated at the end
+    NotDirected,        //topological order only exists for directed graphs
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GraphError::Io(e) => write!(f, "I/O error: {}", e),
+            GraphError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            GraphError::Cycle(nodes) => {
+                let ids: Vec<String> = nodes.iter().map(|n| n.0.to_string()).collect();
+                write!(f, "graph has a cycle: {}", ids.join(" -> "))
+            }
+            GraphError::NotDirected => write!(f, "topological sort needs a directed graph"),
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+impl From<io::Error> for GraphError {
+    fn from(e: io::Error) -> Self {
+        GraphError::Io(e)
+    }
+}
+
+//Result of Dijkstra: the distance to every node and how to get there
+pub struct ShortestPaths<E> {
+    start: NodeId,
+    distance: Vec<Option<E>>,       //None if the node can't be reached
+    previous: Vec<Option<NodeId>>, //the node we came from on the shortest path
+}
+
+impl<E: Copy> ShortestPaths<E> {
+    pub fn distance(&self, to: NodeId) -> Option<E> {
+        self.distance[to.0]
+    }
+
+    //The nodes from the start to `to`, both included. None if `to` can't be reached.
+    pub fn path(&self, to: NodeId) -> Option<Vec<NodeId>> {
+        self.distance[to.0]?;
+        let mut path = vec![to];
+        let mut current = to;
+        while current != self.start {
+            current = self.previous[current.0]?;
+            path.push(current);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<N, E> Graph<N, E> {
+    pub fn directed() -> Graph<N, E> {
+        Graph {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            directed: true,
+        }
+    }
+
+    pub fn undirected() -> Graph<N, E> {
+        Graph {
+            directed: false,
+            ..Graph::directed()
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn add_node(&mut self, value: N) -> NodeId {
+        self.nodes.push(value);
+        self.edges.push(Vec::new());
+        NodeId(self.nodes.len() - 1)
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, value: E)
+    where
+        E: Clone,
+    {
+        if !self.directed && from != to {
+            self.edges[to.0].push((from, value.clone()));
+        }
+        self.edges[from.0].push((to, value));
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id.0]
+    }
+
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    //Finds a node by value. Walks every node, fine for the small graphs here.
+    pub fn find(&self, value: &N) -> Option<NodeId>
+    where
+        N: PartialEq,
+    {
+        self.nodes.iter().position(|n| n == value).map(NodeId)
+    }
+
+    //Neighbours in the order their edges were added
+    pub fn neighbors(&self, id: NodeId) -> impl Iterator<Item = (NodeId, &E)> {
+        self.edges[id.0].iter().map(|(to, e)| (*to, e))
+    }
+
+    //Breadth first: the start, then everything one edge away, then two edges away, ...
+    pub fn bfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        seen[start.0] = true;
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for (next, _) in self.neighbors(node) {
+                if !seen[next.0] {
+                    seen[next.0] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        order
+    }
+
+    //Depth first: follow one path as far as it goes before backing up.
+    //Uses a Vec as the stack instead of recursion, so deep graphs can't overflow the call stack.
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            if seen[node.0] {
+                continue;
+            }
+            seen[node.0] = true;
+            order.push(node);
+            //pushed in reverse so the first neighbour is visited first, like the recursive version
+            for (next, _) in self.edges[node.0].iter().rev() {
+                if !seen[next.0] {
+                    stack.push(*next);
+                }
+            }
+        }
+        order
+    }
+
+    //An order where every edge points forward, e.g. a build order where dependencies come first.
+    //Fails with the offending cycle if there is none.
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>, GraphError> {
+        if !self.directed {
+            return Err(GraphError::NotDirected);
+        }
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            OnPath, //we're somewhere below this node right now, reaching it again means a cycle
+            Done,
+        }
+
+        let mut state = vec![State::New; self.nodes.len()];
+        let mut order = Vec::with_capacity(self.nodes.len());
+        for root in self.node_ids() {
+            if state[root.0] != State::New {
+                continue;
+            }
+            //each stack entry is a node and how many of its edges we have already followed
+            let mut stack = vec![(root, 0)];
+            state[root.0] = State::OnPath;
+            while let Some(&mut (node, ref mut next_edge)) = stack.last_mut() {
+                match self.edges[node.0].get(*next_edge) {
+                    Some(&(next, _)) => {
+                        *next_edge += 1;
+                        match state[next.0] {
+                            State::New => {
+                                state[next.0] = State::OnPath;
+                                stack.push((next, 0));
+                            }
+                            State::OnPath => {
+                                let start = stack.iter().position(|(n, _)| *n == next).unwrap();
+                                let mut cycle: Vec<NodeId> = stack[start..].iter().map(|(n, _)| *n).collect();
+                                cycle.push(next);
+                                return Err(GraphError::Cycle(cycle));
+                            }
+                            State::Done => {}
+                        }
+                    }
+                    None => {
+                        //all of this node's dependents are placed, so it goes before them
+                        state[node.0] = State::Done;
+                        order.push(node);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+        order.reverse();
+        Ok(order)
+    }
+
+    //Groups of nodes that can reach each other, ignoring edge direction. Each group is sorted,
+    //groups are ordered by their smallest node.
+    pub fn connected_components(&self) -> Vec<Vec<NodeId>> {
+        //union-find: every node points towards a representative of its group
+        let mut parent: Vec<usize> = (0..self.nodes.len()).collect();
+        fn root(parent: &mut [usize], mut x: usize) -> usize {
+            while parent[x] != x {
+                parent[x] = parent[parent[x]]; //path halving keeps the chains short
+                x = parent[x];
+            }
+            x
+        }
+        for (from, edges) in self.edges.iter().enumerate() {
+            for (to, _) in edges {
+                let (a, b) = (root(&mut parent, from), root(&mut parent, to.0));
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+
+        let mut groups: HashMap<usize, Vec<NodeId>> = HashMap::new();
+        for node in self.node_ids() {
+            groups.entry(root(&mut parent, node.0)).or_default().push(node);
+        }
+        let mut components: Vec<Vec<NodeId>> = groups.into_values().collect();
+        components.sort();
+        components
+    }
+}
+
+//Edge costs for dijkstra. Plain `+` would panic in debug builds (and wrap around to a tiny,
+//wrong distance in release) when a path gets longer than the type can count.
+pub trait Cost: Copy + Ord + Default {
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_cost {
+    ($($t:ty),*) => {
+        $(impl Cost for $t {
+            fn checked_add(self, other: $t) -> Option<$t> {
+                <$t>::checked_add(self, other)
+            }
+        })*
+    };
+}
+
+impl_cost!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<N, E: Cost> Graph<N, E> {
+    //Cheapest path from `start` to every node, with edge values as (non-negative) costs.
+    //The BinaryHeap always hands us the closest node we haven't finished yet. Instead of a
+    //decrease-key, a node gets pushed again whenever a shorter way to it turns up, and the
+    //outdated entries are skipped when they come out.
+    pub fn dijkstra(&self, start: NodeId) -> ShortestPaths<E> {
+        let mut distance: Vec<Option<E>> = vec![None; self.nodes.len()];
+        let mut previous = vec![None; self.nodes.len()];
+        let mut heap = BinaryHeap::new();
+        distance[start.0] = Some(E::default());
+        heap.push(Reverse((E::default(), start)));
+
+        while let Some(Reverse((d, node))) = heap.pop() {
+            if distance[node.0].is_some_and(|best| d > best) {
+                continue; //outdated entry
+            }
+            for (next, &cost) in self.neighbors(node) {
+                //a path too long to add up can't be the shortest one, skip it
+                let Some(candidate) = d.checked_add(cost) else {
+                    continue;
+                };
+                if distance[next.0].is_none_or(|best| candidate < best) {
+                    distance[next.0] = Some(candidate);
+                    previous[next.0] = Some(node);
+                    heap.push(Reverse((candidate, next)));
+                }
+            }
+        }
+        ShortestPaths {
+            start,
+            distance,
+            previous,
+        }
+    }
+}
+
+impl Graph<String, u64> {
+    //Reads a graph from text with one edge per line: `from to [weight]`.
+    //The weight defaults to 1, blank lines and lines starting with # are skipped.
+    //
+    //    # roads
+    //    home shop 4
+    //    shop school
+    pub fn parse_edge_list(text: &str, directed: bool) -> Result<Graph<String, u64>, GraphError> {
+        let mut graph = if directed { Graph::directed() } else { Graph::undirected() };
+        let mut ids: HashMap<String, NodeId> = HashMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parse_error = |message: String| GraphError::Parse {
+                line: index + 1,
+                message,
+            };
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let (from, to, weight) = match parts.as_slice() {
+                [from, to] => (*from, *to, 1),
+                [from, to, weight] => {
+                    let weight = weight
+                        .parse()
+                        .map_err(|e| parse_error(format!("invalid weight {:?}: {}", weight, e)))?;
+                    (*from, *to, weight)
+                }
+                _ => return Err(parse_error(format!("expected `from to [weight]`, got {:?}", line))),
+            };
+            let mut id = |name: &str| *ids.entry(name.to_string()).or_insert_with(|| graph.add_node(name.to_string()));
+            let (from, to) = (id(from), id(to));
+            graph.add_edge(from, to, weight);
+        }
+        Ok(graph)
+    }
+
+    pub fn load_edge_list(path: impl AsRef<Path>, directed: bool) -> Result<Graph<String, u64>, GraphError> {
+        let text = std::fs::read_to_string(path)?;
+        Graph::parse_edge_list(&text, directed)
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::ops::{Add, Rem};
+
+//The maps the grouping functions can fill. HashMap needs keys that are Hash + Eq,
+//BTreeMap needs keys that are Ord and gives them back sorted. The caller picks one with a type annotation:
+//    let groups: HashMap<_, _> = group_by_key(pairs);
+//    let groups: BTreeMap<_, _> = group_by_key(pairs);
+pub trait KeyedMap<K, V>: Default {
+    //Like map.entry(key).or_default(), the two maps don't share a trait for that
+    fn entry_or_default(&mut self, key: K) -> &mut V
+    where
+        V: Default;
+
+    fn insert_value(&mut self, key: K, value: V);
+}
+
+impl<K: Hash + Eq, V> KeyedMap<K, V> for HashMap<K, V> {
+    fn entry_or_default(&mut self, key: K) -> &mut V
+    where
+        V: Default,
+    {
+        self.entry(key).or_default()
+    }
+
+    fn insert_value(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+}
+
+impl<K: Ord, V> KeyedMap<K, V> for BTreeMap<K, V> {
+    fn entry_or_default(&mut self, key: K) -> &mut V
+    where
+        V: Default,
+    {
+        self.entry(key).or_default()
+    }
+
+    fn insert_value(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+}
+
+//Assignment 1, for any integer type: keeps the even numbers in their original order.
+//From<bool> is only there to get a 0 and a 1 of type T, every integer type has it (From<u8> would leave out i8).
+pub fn filter_even<T>(items: impl IntoIterator<Item = T>) -> Vec<T>
+where
+    T: Copy + PartialEq + Add<Output = T> + Rem<Output = T> + From<bool>,
+{
+    let zero = T::from(false);
+    let one = T::from(true);
+    let two = one + one;
+    items.into_iter().filter(|&x| x % two == zero).collect()
+}
+
+//Assignment 2: every key once, with all of its values in the order they appeared
+pub fn group_by_key<K, V, M>(pairs: impl IntoIterator<Item = (K, V)>) -> M
+where
+    M: KeyedMap<K, Vec<V>>,
+{
+    let mut groups = M::default();
+    for (key, value) in pairs {
+        groups.entry_or_default(key).push(value);
+    }
+    groups
+}
+
+//Same as group_by_key, but the key is computed from each item instead of coming with it
+pub fn partition_by<T, K, M>(items: impl IntoIterator<Item = T>, mut key: impl FnMut(&T) -> K) -> M
+where
+    M: KeyedMap<K, Vec<T>>,
+{
+    group_by_key(items.into_iter().map(|item| (key(&item), item)))
+}
+
+//How many items share each key, e.g. count_by(words, |w| w.len()) for a word length histogram
+pub fn count_by<T, K, M>(items: impl IntoIterator<Item = T>, mut key: impl FnMut(&T) -> K) -> M
+where
+    M: KeyedMap<K, usize>,
+{
+    let mut counts = M::default();
+    for item in items {
+        *counts.entry_or_default(key(&item)) += 1;
+    }
+    counts
+}
+
+//Looks items up by a key, e.g. index_by(users, |u| u.id.clone()).
+//Keys should be unique, if they aren't the last item with that key wins (just like HashMap::insert).
+pub fn index_by<T, K, M>(items: impl IntoIterator<Item = T>, mut key: impl FnMut(&T) -> K) -> M
+where
+    M: KeyedMap<K, T>,
+{
+    let mut index = M::default();
+    for item in items {
+        index.insert_value(key(&item), item);
+    }
+    index
+}
@@ -0,0 +1,70 @@
+pub mod cache;
+pub mod graph;
+pub mod grouping;
+pub mod my_vec;
+pub mod open_map;
+pub mod persist;
+pub mod priority_queue;
+pub mod registry;
+pub mod repl;
+pub mod scheduler;
+pub mod sketch;
+pub mod trie;
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    let mut vec = Vec::new(); //Initialize a new empty vector
+    //Pushing past the capacity makes the vector reallocate, src/my_vec.rs is a hand-written Vec that shows how
+    vec.push(1); //Add an element to the vector
+    vec.push(2);
+    vec.push(3);
+    writeln!(out, "{:?}", vec)?; //Printing format for vectors, because its kinda a struct underneath so {} doesn't work, we use {:?} instead which is a debug trait
+    writeln!(out, "First element: {}", vec[0])?; //Accessing the first element using indexing
+
+    //Everything is gone when the program exits, src/persist.rs saves vectors and users as JSON, CSV or binary
+
+    //Another way to initialize a vector is to use vec! macro
+    // let vec2 = vec![1, 2, 3, 4, 5]; //This is a macro that initializes a vector with the given elements
+
+
+    //This map grows forever, src/cache.rs has LruCache and TtlCache for when it has to stay small
+    //Curious what HashMap::new() does inside? src/open_map.rs builds one from scratch, `cargo run --release -- bench` races them
+    let mut users:HashMap<String,i32> = HashMap::new();
+
+    users.insert(String::from("Ayush"), 21);
+    users.insert(String::from("John"), 22);
+
+    let first_user_age = users.get("Ayush"); //Return type is Option<&i32>, because the key might not exist in the HashMap
+
+    //src/registry.rs wraps this map in a UserRegistry (case-insensitive names, age queries, errors for duplicates),
+    //`cargo run -p collections -- repl` lets you play with it
+    match first_user_age {
+        Some(age) => writeln!(out, "Ayush's age is {}", age)?,
+        None => writeln!(out, "User not found")?,
+    }
+
+
+    Ok(())
+}
+//Not covered above: heaps. src/priority_queue.rs and src/scheduler.rs use them, `cargo run -- schedule` compares scheduling policies
+
+//Assignment 1: Write a function that takes a vector and returns a new vector with even numbers only
+
+//Assignment 2: Write a function that takes a vector of tuples(each tuple contains a key and a value) and returns a HashMap where the keys are unique keys from input tuples and values are vectors of all corresponding values assoiciated with each key. 
+//Generic versions of both (and a few more helpers) live in src/grouping.rs, try the exercises first though
+//Solve both in exercises/assignments/12_filter_even.rs and 12_group_by_key.rs, `cargo run -p exercises -- watch` checks them every time you save
+
+pub struct Collections;
+
+impl bootcamp_core::Lesson for Collections {
+    fn dir(&self) -> &'static str {
+        "12_Collections"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
@@ -1,31 +1,138 @@
+use bootcamp_core::input;
+use bootcamp_core::output::Table;
+use collections::open_map::OpenMap;
+use collections::registry::UserRegistry;
+use collections::trie::Trie;
 use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
 
+//`cargo run` for the lesson, `cargo run -- repl` for the interactive user registry,
+//`cargo run --release -- bench` to compare our OpenMap with std's HashMap,
+//`cargo run -- complete` to autocomplete words from the course notes,
+//`cargo run -- schedule` to compare job scheduling policies
 fn main() {
-    let mut vec = Vec::new(); //Initialize a new empty vector
-    vec.push(1); //Add an element to the vector
-    vec.push(2);
-    vec.push(3);
-    println!("{:?}", vec); //Printing format for vectors, because its kinda a struct underneath so {} doesn't work, we use {:?} instead which is a debug trait
-    println!("First element: {}", vec[0]); //Accessing the first element using indexing
+    match input::args().first().map(|s| s.as_str()) {
+        Some("repl") => {
+            let mut registry = UserRegistry::new();
+            collections::repl::run(&mut registry, &mut std::io::stdin().lock(), &mut std::io::stdout()).unwrap();
+        }
+        Some("bench") => bench(),
+        Some("complete") => complete(),
+        Some("schedule") => {
+            let jobs = collections::scheduler::sample_jobs();
+            collections::scheduler::compare(&jobs, &mut std::io::stdout()).unwrap();
+        }
+        _ => collections::run(&mut std::io::stdout()).unwrap(),
+    }
+}
 
-    //Another way to initialize a vector is to use vec! macro
-    // let vec2 = vec![1, 2, 3, 4, 5]; //This is a macro that initializes a vector with the given elements
+//The operations both maps have, so every workload runs the same code on each
+trait BenchMap {
+    fn insert(&mut self, key: u64, value: u64);
+    fn get(&self, key: u64) -> Option<u64>;
+    fn remove(&mut self, key: u64) -> Option<u64>;
+}
 
+impl BenchMap for HashMap<u64, u64> {
+    fn insert(&mut self, key: u64, value: u64) {
+        HashMap::insert(self, key, value);
+    }
+    fn get(&self, key: u64) -> Option<u64> {
+        HashMap::get(self, &key).copied()
+    }
+    fn remove(&mut self, key: u64) -> Option<u64> {
+        HashMap::remove(self, &key)
+    }
+}
 
-    let mut users:HashMap<String,i32> = HashMap::new();
+impl BenchMap for OpenMap<u64, u64> {
+    fn insert(&mut self, key: u64, value: u64) {
+        OpenMap::insert(self, key, value);
+    }
+    fn get(&self, key: u64) -> Option<u64> {
+        OpenMap::get(self, &key).copied()
+    }
+    fn remove(&mut self, key: u64) -> Option<u64> {
+        OpenMap::remove(self, &key)
+    }
+}
 
-    users.insert(String::from("Ayush"), 21);
-    users.insert(String::from("John"), 22);
+//Times insert, lookup (half of them misses) and remove of `count` scattered keys
+fn run_workloads(map: &mut impl BenchMap, count: u64) -> [Duration; 3] {
+    //multiplying by an odd constant scatters the keys without repeating any
+    let key = |i: u64| i.wrapping_mul(0x9E37_79B9_7F4A_7C15);
 
-    let first_user_age = users.get("Ayush"); //Return type is Option<&i32>, because the key might not exist in the HashMap
+    let start = Instant::now();
+    for i in 0..count {
+        map.insert(key(i), i);
+    }
+    let insert = start.elapsed();
 
-    match first_user_age {
-        Some(age) => println!("Ayush's age is {}", age),
-        None => println!("User not found"),
+    let start = Instant::now();
+    let mut found = 0;
+    for i in 0..count * 2 {
+        found += map.get(key(i)).is_some() as u64;
     }
+    let lookup = start.elapsed();
+    assert_eq!(black_box(found), count);
 
+    let start = Instant::now();
+    for i in 0..count {
+        black_box(map.remove(key(i)));
+    }
+    let remove = start.elapsed();
+
+    [insert, lookup, remove]
+}
 
+fn bench() {
+    let count = 1_000_000;
+    let mut table = Table::new(&["Map", "Insert", "Lookup", "Remove"]);
+    let mut add_row = |name: &str, times: [Duration; 3]| {
+        let cells: Vec<String> = times.iter().map(|t| format!("{:.1} ms", t.as_secs_f64() * 1000.0)).collect();
+        table.row(&[name, &cells[0], &cells[1], &cells[2]]);
+    };
+    add_row("std HashMap", run_workloads(&mut HashMap::new(), count));
+    add_row("OpenMap", run_workloads(&mut OpenMap::new(), count));
+    println!("{} keys, {} lookups", count, count * 2);
+    table.write_to(&mut std::io::stdout()).unwrap();
 }
-//Assignment 1: Write a function that takes a vector and returns a new vector with even numbers only
 
-//Assignment 2: Write a function that takes a vector of tuples(each tuple contains a key and a value) and returns a HashMap where the keys are unique keys from input tuples and values are vectors of all corresponding values assoiciated with each key. 
\ No newline at end of file
+//Counts every word in the NOTES files, then suggests the most used words for each prefix you type
+fn complete() {
+    let notes_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+    let mut words: Trie<usize> = Trie::new();
+    for entry in std::fs::read_dir(notes_dir).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        if !(name.starts_with("NOTES") && name.ends_with(".md")) {
+            continue;
+        }
+        let text = std::fs::read_to_string(&path).unwrap();
+        for word in text.split(|c: char| !c.is_alphabetic()).filter(|w| w.len() >= 3) {
+            let word = word.to_lowercase();
+            match words.get_mut(&word) {
+                Some(count) => *count += 1,
+                None => {
+                    words.insert(&word, 1);
+                }
+            }
+        }
+    }
+    println!("{} different words in the notes, type the start of one (empty line to quit)", words.len());
+
+    while let Ok(Some(prefix)) = input::prompt("prefix> ") {
+        let prefix = prefix.trim().to_lowercase();
+        if prefix.is_empty() {
+            break;
+        }
+        let suggestions = words.autocomplete(&prefix, 5, |&count| count);
+        if suggestions.is_empty() {
+            println!("nothing starts with {:?}", prefix);
+        }
+        for (word, count) in suggestions {
+            println!("  {:<20} {} times", word, count);
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::alloc::{self, Layout};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Bound, Deref, DerefMut, RangeBounds};
+use std::ptr::{self, NonNull};
+
+//A Vec written by hand to see what happens when `vec.push(1)` in the lesson runs out of room.
+//
+//A Vec is three things on the stack: a pointer to a heap buffer, how many elements fit in that
+//buffer (capacity) and how many are actually in it (length). When a push finds length == capacity,
+//a buffer twice as big is allocated, the elements are moved over and the old buffer is freed.
+//That's the "reallocation" from 5_Stack-and-Heap, and why the pointer printed there can change.
+//
+//Everything below works on raw memory, so it needs `unsafe`. Every unsafe block says why it's fine.
+pub struct MyVec<T> {
+    ptr: NonNull<T>, //dangling (but well aligned) while nothing is allocated
+    cap: usize,
+    len: usize,
+    on_grow: Option<Box<dyn FnMut(Growth) + Send>>,
+    _owns: PhantomData<T>, //tells the compiler we own Ts, so dropping us may drop Ts
+}
+
+//What the instrumented mode reports every time the buffer is reallocated
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Growth {
+    pub len: usize,
+    pub old_capacity: usize,
+    pub new_capacity: usize,
+    pub moved: bool, //false if the allocator managed to grow the buffer in place
+}
+
+//MyVec owns its Ts just like Vec does, so it can cross threads whenever T can.
+//The callback is only ever called through &mut self, so sharing &MyVec can't reach it.
+unsafe impl<T: Send> Send for MyVec<T> {}
+unsafe impl<T: Sync> Sync for MyVec<T> {}
+
+impl<T> MyVec<T> {
+    //Zero-sized types (like `()`) need no memory at all, so they get "infinite" capacity
+    const IS_ZST: bool = mem::size_of::<T>() == 0;
+
+    pub fn new() -> MyVec<T> {
+        MyVec {
+            ptr: NonNull::dangling(),
+            cap: if Self::IS_ZST { usize::MAX } else { 0 },
+            len: 0,
+            on_grow: None,
+            _owns: PhantomData,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> MyVec<T> {
+        let mut v = MyVec::new();
+        if !Self::IS_ZST && capacity > 0 {
+            v.grow_to(capacity);
+        }
+        v
+    }
+
+    //Instrumented mode: `callback` is told about every reallocation
+    pub fn on_grow(mut self, callback: impl FnMut(Growth) + Send + 'static) -> Self {
+        self.on_grow = Some(Box::new(callback));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr.as_ptr()
+    }
+
+    pub fn push(&mut self, value: T) {
+        if self.len == self.cap {
+            self.grow();
+        }
+        //SAFETY: len < cap, so the slot at `len` is inside the buffer and holds nothing yet
+        unsafe { self.ptr.as_ptr().add(self.len).write(value) };
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        //SAFETY: the slot at the old last index was initialised, and lowering len first means
+        //it won't be read or dropped again
+        Some(unsafe { self.ptr.as_ptr().add(self.len).read() })
+    }
+
+    //Shifts everything from `index` one to the right, like Vec::insert. Panics if index > len.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len, "insert index {} is out of bounds (len {})", index, self.len);
+        if self.len == self.cap {
+            self.grow();
+        }
+        //SAFETY: index <= len < cap, so both the moved range and its destination fit in the buffer.
+        //ptr::copy handles the overlap (it's memmove).
+        unsafe {
+            let slot = self.ptr.as_ptr().add(index);
+            ptr::copy(slot, slot.add(1), self.len - index);
+            slot.write(value);
+        }
+        self.len += 1;
+    }
+
+    //Takes the element at `index` out and shifts the rest left. Panics if index >= len.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "remove index {} is out of bounds (len {})", index, self.len);
+        self.len -= 1;
+        //SAFETY: index was a valid element, it's read out exactly once and the hole is
+        //closed by moving the (old) len - index - 1 elements after it
+        unsafe {
+            let slot = self.ptr.as_ptr().add(index);
+            let value = slot.read();
+            ptr::copy(slot.add(1), slot, self.len - index);
+            value
+        }
+    }
+
+    pub fn clear(&mut self) {
+        let len = self.len;
+        //set len first: if an element's drop panics the rest are leaked instead of dropped twice
+        self.len = 0;
+        //SAFETY: the first `len` slots were initialised and are now outside the vector
+        unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), len)) };
+    }
+
+    //Removes `range` and yields the removed elements. Whatever the Drain doesn't yield is dropped
+    //when it is, and the elements after the range then slide left to close the gap.
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Drain<'_, T> {
+        //None when the bound is past usize::MAX, like `..=usize::MAX`, which is out of bounds anyway
+        let start = match range.start_bound() {
+            Bound::Included(&s) => Some(s),
+            Bound::Excluded(&s) => s.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e.checked_add(1),
+            Bound::Excluded(&e) => Some(e),
+            Bound::Unbounded => Some(self.len),
+        };
+        let (start, end) = match (start, end) {
+            (Some(start), Some(end)) if start <= end && end <= self.len => (start, end),
+            _ => panic!(
+                "drain range ({:?}, {:?}) out of bounds (len {})",
+                range.start_bound(),
+                range.end_bound(),
+                self.len
+            ),
+        };
+
+        let tail_len = self.len - end;
+        //while draining the vector only admits to the elements before the range.
+        //If the Drain is leaked (mem::forget) we lose the rest, but never touch them twice.
+        self.len = start;
+        Drain {
+            vec: self,
+            front: start,
+            back: end,
+            tail_start: end,
+            tail_len,
+        }
+    }
+
+    fn grow(&mut self) {
+        //a ZST vector has capacity usize::MAX, so getting here means len overflowed
+        assert!(!Self::IS_ZST, "capacity overflow");
+        //start at 4 like Vec does for small types, then double
+        let new_cap = if self.cap == 0 { 4 } else { self.cap.checked_mul(2).expect("capacity overflow") };
+        self.grow_to(new_cap);
+    }
+
+    fn grow_to(&mut self, new_cap: usize) {
+        let new_layout = Layout::array::<T>(new_cap).expect("capacity overflow");
+        let old_ptr = self.ptr.as_ptr();
+        let new_ptr = if self.cap == 0 {
+            //SAFETY: new_layout has a non-zero size (T isn't a ZST and new_cap > 0)
+            unsafe { alloc::alloc(new_layout) }
+        } else {
+            let old_layout = Layout::array::<T>(self.cap).unwrap();
+            //SAFETY: the buffer was allocated with old_layout by this allocator, and new_layout
+            //passed the size checks in Layout::array
+            unsafe { alloc::realloc(old_ptr as *mut u8, old_layout, new_layout.size()) }
+        };
+        self.ptr = match NonNull::new(new_ptr as *mut T) {
+            Some(p) => p,
+            None => alloc::handle_alloc_error(new_layout),
+        };
+
+        let growth = Growth {
+            len: self.len,
+            old_capacity: self.cap,
+            new_capacity: new_cap,
+            moved: self.cap > 0 && self.ptr.as_ptr() != old_ptr,
+        };
+        self.cap = new_cap;
+        if let Some(callback) = self.on_grow.as_mut() {
+            callback(growth);
+        }
+    }
+}
+
+impl<T> Drop for MyVec<T> {
+    fn drop(&mut self) {
+        self.clear();
+        if !Self::IS_ZST && self.cap > 0 {
+            //SAFETY: allocated in grow_to with exactly this layout
+            unsafe { alloc::dealloc(self.ptr.as_ptr() as *mut u8, Layout::array::<T>(self.cap).unwrap()) };
+        }
+    }
+}
+
+impl<T> Default for MyVec<T> {
+    fn default() -> Self {
+        MyVec::new()
+    }
+}
+
+//Deref to a slice gives us indexing, len checks, iter(), sort() and everything else slices have
+impl<T> Deref for MyVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        //SAFETY: the first `len` slots are initialised, and ptr is non-null and aligned even when dangling
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for MyVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        //SAFETY: as in deref, and &mut self means nobody else is looking
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> Extend<T> for MyVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for MyVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut v = MyVec::new();
+        v.extend(iter);
+        v
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+pub struct Drain<'a, T> {
+    vec: &'a mut MyVec<T>,
+    front: usize, //next index to yield from the front
+    back: usize,  //one past the next index to yield from the back
+    tail_start: usize,
+    tail_len: usize,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        //SAFETY: front..back are initialised elements nobody else will read
+        let value = unsafe { self.vec.ptr.as_ptr().add(self.front).read() };
+        self.front += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.back - self.front;
+        (left, Some(left))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        //SAFETY: as in next
+        Some(unsafe { self.vec.ptr.as_ptr().add(self.back).read() })
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        //drop whatever wasn't yielded, then slide the tail left over the gap
+        let base = self.vec.ptr.as_ptr();
+        //SAFETY: front..back are still initialised and outside the vector's len
+        unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(base.add(self.front), self.back - self.front)) };
+        let start = self.vec.len;
+        //SAFETY: the tail is tail_len initialised elements at tail_start, and start <= tail_start
+        unsafe { ptr::copy(base.add(self.tail_start), base.add(start), self.tail_len) };
+        self.vec.len = start + self.tail_len;
+    }
+}
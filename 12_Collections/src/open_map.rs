@@ -0,0 +1,431 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+
+//A HashMap written from scratch, to see what `HashMap::new()` from the lesson does underneath.
+//
+//Open addressing: all entries live directly in one Vec of slots. A key's hash picks its "home" slot,
+//if that is taken we walk right (wrapping around) until we find it or hit an empty slot.
+//
+//Robin Hood hashing: while walking right to insert, every entry we pass has a probe distance
+//(how far it is from its own home). If the new entry is already further from home than the one
+//sitting in a slot, they swap and we carry on inserting the poorer one. That keeps probe distances
+//short and even, and lets lookups stop early: once we reach an entry closer to home than we are,
+//our key can't be further along.
+//
+//Backward shift deletion: instead of leaving a "deleted" marker (tombstone) behind, removal slides
+//the following entries one slot left until one is already at home or a slot is empty.
+pub struct OpenMap<K, V, S = RandomState> {
+    slots: Vec<Option<Slot<K, V>>>, //length is always 0 or a power of two
+    len: usize,
+    hasher: S,
+}
+
+struct Slot<K, V> {
+    hash: u64, //kept so growing and probing don't need to hash the key again
+    key: K,
+    value: V,
+}
+
+const MIN_CAPACITY: usize = 8;
+
+impl<K, V> OpenMap<K, V, RandomState> {
+    pub fn new() -> OpenMap<K, V, RandomState> {
+        OpenMap::with_hasher(RandomState::new())
+    }
+
+    pub fn with_capacity(capacity: usize) -> OpenMap<K, V, RandomState> {
+        OpenMap::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K, V> Default for OpenMap<K, V, RandomState> {
+    fn default() -> Self {
+        OpenMap::new()
+    }
+}
+
+impl<K, V, S> OpenMap<K, V, S> {
+    //Like HashMap, nothing is allocated until the first insert
+    pub fn with_hasher(hasher: S) -> OpenMap<K, V, S> {
+        OpenMap {
+            slots: Vec::new(),
+            len: 0,
+            hasher,
+        }
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> OpenMap<K, V, S> {
+        let mut map = OpenMap::with_hasher(hasher);
+        if capacity > 0 {
+            map.slots = empty_slots(slots_for(capacity));
+        }
+        map
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    //How many entries fit before the next resize
+    pub fn capacity(&self) -> usize {
+        max_len(self.slots.len())
+    }
+
+    //Fraction of slots in use
+    pub fn load_factor(&self) -> f64 {
+        if self.slots.is_empty() { 0.0 } else { self.len as f64 / self.slots.len() as f64 }
+    }
+
+    //The furthest any entry sits from its home slot, i.e. the worst case lookup length
+    pub fn max_probe_distance(&self) -> usize {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| slot.as_ref().map(|s| self.distance(s.hash, index)))
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.slots.iter().flatten().map(|s| (&s.key, &s.value))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        self.slots.iter_mut().flatten().map(|s| (&s.key, &mut s.value))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    //Keeps the allocated slots
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+        self.len = 0;
+    }
+
+    fn mask(&self) -> usize {
+        self.slots.len() - 1
+    }
+
+    fn home(&self, hash: u64) -> usize {
+        hash as usize & self.mask()
+    }
+
+    //How far slot `index` is from the home slot of `hash`, counting the wrap around
+    fn distance(&self, hash: u64, index: usize) -> usize {
+        index.wrapping_sub(self.home(hash)) & self.mask()
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> OpenMap<K, V, S> {
+    //Inserts or replaces, returning the old value like HashMap::insert
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        self.slots[index].as_ref().map(|s| &s.value)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        self.slots[index].as_mut().map(|s| &mut s.value)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        Some(self.remove_at(index).1)
+    }
+
+    //Finds the slot for `key`, growing first if a new entry wouldn't fit
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        let hash = self.hasher.hash_one(&key);
+        if self.len > 0
+            && let Some(index) = self.find_hashed(hash, &key)
+        {
+            return Entry::Occupied(OccupiedEntry { map: self, index });
+        }
+        //only a new key needs room, looking at or replacing an existing one never resizes
+        if self.len + 1 > self.capacity() {
+            self.grow();
+        }
+        Entry::Vacant(VacantEntry { map: self, hash, key })
+    }
+
+    //Makes room for `additional` more entries without resizing in between
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.len + additional;
+        if needed > self.capacity() {
+            self.resize(slots_for(needed));
+        }
+    }
+
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.len == 0 {
+            return None;
+        }
+        self.find_hashed(self.hasher.hash_one(key), key)
+    }
+
+    fn find_hashed<Q>(&self, hash: u64, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let mut index = self.home(hash);
+        let mut distance = 0;
+        loop {
+            let slot = self.slots[index].as_ref()?;
+            //Robin Hood early exit: our key would have displaced this entry if it were stored here or later
+            if self.distance(slot.hash, index) < distance {
+                return None;
+            }
+            if slot.hash == hash && slot.key.borrow() == key {
+                return Some(index);
+            }
+            index = (index + 1) & self.mask();
+            distance += 1;
+        }
+    }
+
+    //Puts a key that isn't in the map yet into its place and returns where it ended up.
+    //There must be at least one empty slot.
+    fn insert_new(&mut self, hash: u64, key: K, value: V) -> usize {
+        let mask = self.mask();
+        let mut carry = Slot { hash, key, value };
+        let mut index = self.home(hash);
+        let mut distance = 0;
+        let mut placed_at = None;
+        loop {
+            let existing_distance = match &self.slots[index] {
+                None => {
+                    self.slots[index] = Some(carry);
+                    self.len += 1;
+                    return placed_at.unwrap_or(index);
+                }
+                Some(existing) => self.distance(existing.hash, index),
+            };
+            if existing_distance < distance {
+                //the entry here is richer (closer to home) than the one we carry: swap and keep going
+                let existing = self.slots[index].as_mut().unwrap();
+                std::mem::swap(existing, &mut carry);
+                placed_at.get_or_insert(index);
+                distance = existing_distance;
+            }
+            index = (index + 1) & mask;
+            distance += 1;
+        }
+    }
+
+    fn grow(&mut self) {
+        let slots = (self.slots.len() * 2).max(MIN_CAPACITY);
+        self.resize(slots);
+    }
+
+    fn resize(&mut self, slots: usize) {
+        let old = std::mem::replace(&mut self.slots, empty_slots(slots));
+        self.len = 0;
+        for slot in old.into_iter().flatten() {
+            self.insert_new(slot.hash, slot.key, slot.value);
+        }
+    }
+}
+
+impl<K, V, S> OpenMap<K, V, S> {
+    //Backward shift: pull every following entry that isn't at home one slot to the left
+    fn remove_at(&mut self, index: usize) -> (K, V) {
+        let removed = self.slots[index].take().expect("remove_at called on an empty slot");
+        self.len -= 1;
+        let mask = self.mask();
+        let mut hole = index;
+        loop {
+            let next = (hole + 1) & mask;
+            match &self.slots[next] {
+                Some(slot) if self.distance(slot.hash, next) > 0 => {
+                    self.slots[hole] = self.slots[next].take();
+                    hole = next;
+                }
+                _ => break,
+            }
+        }
+        (removed.key, removed.value)
+    }
+}
+
+//Keep the load factor at or below 7/8, past that probe sequences get long
+fn max_len(slots: usize) -> usize {
+    slots / 8 * 7
+}
+
+fn slots_for(len: usize) -> usize {
+    let mut slots = MIN_CAPACITY;
+    while max_len(slots) < len {
+        slots *= 2;
+    }
+    slots
+}
+
+fn empty_slots<K, V>(count: usize) -> Vec<Option<Slot<K, V>>> {
+    std::iter::repeat_with(|| None).take(count).collect()
+}
+
+//The entry API, same idea as std's: look the key up once, then decide what to do with the slot
+pub enum Entry<'a, K, V, S> {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+pub struct OccupiedEntry<'a, K, V, S> {
+    map: &'a mut OpenMap<K, V, S>,
+    index: usize,
+}
+
+pub struct VacantEntry<'a, K, V, S> {
+    map: &'a mut OpenMap<K, V, S>,
+    hash: u64,
+    key: K,
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher> Entry<'a, K, V, S> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => &entry.key,
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    pub fn and_modify(mut self, f: impl FnOnce(&mut V)) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, V, S> OccupiedEntry<'a, K, V, S> {
+    fn slot(&self) -> &Slot<K, V> {
+        self.map.slots[self.index].as_ref().unwrap()
+    }
+
+    fn slot_mut(&mut self) -> &mut Slot<K, V> {
+        self.map.slots[self.index].as_mut().unwrap()
+    }
+
+    pub fn key(&self) -> &K {
+        &self.slot().key
+    }
+
+    pub fn get(&self) -> &V {
+        &self.slot().value
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.slot_mut().value
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.slots[self.index].as_mut().unwrap().value
+    }
+
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    pub fn remove(self) -> V {
+        self.map.remove_at(self.index).1
+    }
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher> VacantEntry<'a, K, V, S> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn insert(self, value: V) -> &'a mut V {
+        //entry() already grew the map, so there is room
+        let index = self.map.insert_new(self.hash, self.key, value);
+        &mut self.map.slots[index].as_mut().unwrap().value
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> FromIterator<(K, V)> for OpenMap<K, V, S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = OpenMap::with_hasher(S::default());
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Extend<(K, V)> for OpenMap<K, V, S> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for OpenMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
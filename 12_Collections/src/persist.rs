@@ -0,0 +1,348 @@
+use crate::registry::{RegistryError, User, UserRegistry};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+//Three ways to keep the lesson's data around after the program exits:
+//  JSON   - readable, what most web APIs speak
+//  CSV    - opens in a spreadsheet, one row per user/number
+//  Binary - our own compact format, see the layout below
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Json,
+    Csv,
+    Binary,
+}
+
+impl Format {
+    //Picks the format from a file extension: .json, .csv, anything else is binary
+    pub fn from_path(path: &Path) -> Format {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Format::Json,
+            Some("csv") => Format::Csv,
+            _ => Format::Binary,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PersistError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Csv(csv::Error),
+    NotOurFormat,                                      //the magic bytes are wrong
+    UnsupportedVersion { found: u16, supported: u16 }, //written by a newer version of this program
+    WrongKind { found: u8, expected: u8 },             //e.g. loading a numbers file as users
+    ChecksumMismatch { stored: u32, computed: u32 },
+    Corrupt(String),
+    Invalid(RegistryError), //the file parsed fine but the users in it don't make a valid registry
+}
+
+impl fmt::Display for PersistError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PersistError::Io(e) => write!(f, "I/O error: {}", e),
+            PersistError::Json(e) => write!(f, "invalid JSON: {}", e),
+            PersistError::Csv(e) => write!(f, "invalid CSV: {}", e),
+            PersistError::NotOurFormat => write!(f, "not a collections data file"),
+            PersistError::UnsupportedVersion { found, supported } => write!(
+                f,
+                "file uses format version {} but this program only understands up to version {}, \
+                 it was probably written by a newer version",
+                found, supported
+            ),
+            PersistError::WrongKind { found, expected } => {
+                write!(f, "file holds {} but {} were expected", kind_name(*found), kind_name(*expected))
+            }
+            PersistError::ChecksumMismatch { stored, computed } => write!(
+                f,
+                "checksum mismatch (stored {:08x}, computed {:08x}), the file is damaged",
+                stored, computed
+            ),
+            PersistError::Corrupt(message) => write!(f, "corrupt file: {}", message),
+            PersistError::Invalid(e) => write!(f, "invalid data: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PersistError {}
+
+impl From<io::Error> for PersistError {
+    fn from(e: io::Error) -> Self {
+        PersistError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for PersistError {
+    fn from(e: serde_json::Error) -> Self {
+        PersistError::Json(e)
+    }
+}
+
+impl From<csv::Error> for PersistError {
+    fn from(e: csv::Error) -> Self {
+        PersistError::Csv(e)
+    }
+}
+
+impl From<RegistryError> for PersistError {
+    fn from(e: RegistryError) -> Self {
+        PersistError::Invalid(e)
+    }
+}
+
+//Anything from the lesson that can be saved in all three formats
+pub trait Persist: Sized {
+    const KIND: u8; //stored in the binary header so a users file can't be loaded as numbers
+
+    fn save(&self, format: Format, out: &mut impl Write) -> Result<(), PersistError>;
+    fn load(format: Format, input: &mut impl Read) -> Result<Self, PersistError>;
+
+    fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), PersistError> {
+        let path = path.as_ref();
+        let mut out = BufWriter::new(File::create(path)?);
+        self.save(Format::from_path(path), &mut out)?;
+        out.flush()?;
+        Ok(())
+    }
+
+    fn load_from_file(path: impl AsRef<Path>) -> Result<Self, PersistError> {
+        let path = path.as_ref();
+        let mut input = BufReader::new(File::open(path)?);
+        Self::load(Format::from_path(path), &mut input)
+    }
+}
+
+//The user map is saved as a list of users, youngest first, so files don't change order between runs
+impl Persist for UserRegistry {
+    const KIND: u8 = 1;
+
+    fn save(&self, format: Format, out: &mut impl Write) -> Result<(), PersistError> {
+        let users = self.all();
+        match format {
+            Format::Json => serde_json::to_writer_pretty(out, &users)?,
+            Format::Csv => {
+                let mut writer = csv::Writer::from_writer(out);
+                for user in users {
+                    writer.serialize(user)?;
+                }
+                writer.flush()?;
+            }
+            Format::Binary => {
+                let mut payload = Vec::new();
+                put_len(&mut payload, users.len())?;
+                for user in users {
+                    let name = user.name.as_bytes();
+                    let len = u16::try_from(name.len())
+                        .map_err(|_| PersistError::Corrupt(format!("name {:?} is too long", user.name)))?;
+                    payload.extend_from_slice(&len.to_le_bytes());
+                    payload.extend_from_slice(name);
+                    payload.extend_from_slice(&user.age.to_le_bytes());
+                }
+                write_binary(out, Self::KIND, &payload)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn load(format: Format, input: &mut impl Read) -> Result<Self, PersistError> {
+        let users: Vec<User> = match format {
+            Format::Json => serde_json::from_reader(input)?,
+            Format::Csv => csv::Reader::from_reader(input).deserialize().collect::<Result<_, _>>()?,
+            Format::Binary => {
+                let payload = read_binary(input, Self::KIND)?;
+                let mut reader = PayloadReader { bytes: &payload };
+                let count = reader.u32()?;
+                let mut users = Vec::new();
+                for _ in 0..count {
+                    let len = u16::from_le_bytes(reader.take()?) as usize;
+                    let name = String::from_utf8(reader.bytes(len)?.to_vec())
+                        .map_err(|_| PersistError::Corrupt(String::from("name is not valid UTF-8")))?;
+                    let age = i32::from_le_bytes(reader.take()?);
+                    users.push(User { name, age });
+                }
+                reader.finish()?;
+                users
+            }
+        };
+        Ok(UserRegistry::from_users(users)?)
+    }
+}
+
+//Vectors like the one at the start of the lesson
+impl Persist for Vec<i32> {
+    const KIND: u8 = 2;
+
+    fn save(&self, format: Format, out: &mut impl Write) -> Result<(), PersistError> {
+        match format {
+            Format::Json => serde_json::to_writer(out, self)?,
+            Format::Csv => {
+                //csv only writes headers for structs, so the "value" header is written by hand
+                let mut writer = csv::WriterBuilder::new().has_headers(false).from_writer(out);
+                writer.write_record(["value"])?;
+                for n in self {
+                    writer.serialize(n)?;
+                }
+                writer.flush()?;
+            }
+            Format::Binary => {
+                let mut payload = Vec::new();
+                put_len(&mut payload, self.len())?;
+                for n in self {
+                    payload.extend_from_slice(&n.to_le_bytes());
+                }
+                write_binary(out, Self::KIND, &payload)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn load(format: Format, input: &mut impl Read) -> Result<Self, PersistError> {
+        match format {
+            Format::Json => Ok(serde_json::from_reader(input)?),
+            Format::Csv => Ok(csv::Reader::from_reader(input).deserialize().collect::<Result<_, _>>()?),
+            Format::Binary => {
+                let payload = read_binary(input, Self::KIND)?;
+                let mut reader = PayloadReader { bytes: &payload };
+                let count = reader.u32()?;
+                let mut numbers = Vec::new();
+                for _ in 0..count {
+                    numbers.push(i32::from_le_bytes(reader.take()?));
+                }
+                reader.finish()?;
+                Ok(numbers)
+            }
+        }
+    }
+}
+
+//Binary layout, all numbers little endian:
+//  magic      4 bytes  "BCOL"
+//  version    u16      FORMAT_VERSION
+//  kind       u8       Persist::KIND
+//  length     u32      payload size in bytes
+//  payload    ...      u32 count, then the items
+//  checksum   u32      CRC-32 of everything above
+//Bump FORMAT_VERSION whenever the layout changes, older programs will then refuse the file
+//instead of reading garbage.
+const MAGIC: &[u8; 4] = b"BCOL";
+pub const FORMAT_VERSION: u16 = 1;
+const HEADER_LEN: usize = 4 + 2 + 1 + 4;
+
+fn put_len(payload: &mut Vec<u8>, len: usize) -> Result<(), PersistError> {
+    let len = u32::try_from(len).map_err(|_| PersistError::Corrupt(String::from("too many items")))?;
+    payload.extend_from_slice(&len.to_le_bytes());
+    Ok(())
+}
+
+fn write_binary(out: &mut impl Write, kind: u8, payload: &[u8]) -> Result<(), PersistError> {
+    //the length field is a u32, a bigger payload couldn't be read back
+    let len = u32::try_from(payload.len()).map_err(|_| PersistError::Corrupt(String::from("payload over 4 GiB")))?;
+    let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len() + 4);
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    bytes.push(kind);
+    bytes.extend_from_slice(&len.to_le_bytes());
+    bytes.extend_from_slice(payload);
+    let checksum = crc32(&bytes);
+    bytes.extend_from_slice(&checksum.to_le_bytes());
+    out.write_all(&bytes)?;
+    Ok(())
+}
+
+//Checks the header and checksum and returns just the payload
+fn read_binary(input: &mut impl Read, kind: u8) -> Result<Vec<u8>, PersistError> {
+    let mut bytes = Vec::new();
+    input.read_to_end(&mut bytes)?;
+    if bytes.len() < HEADER_LEN + 4 || &bytes[..4] != MAGIC {
+        return Err(PersistError::NotOurFormat);
+    }
+    //the version is checked before the checksum: a newer version might checksum differently
+    let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+    if version > FORMAT_VERSION {
+        return Err(PersistError::UnsupportedVersion {
+            found: version,
+            supported: FORMAT_VERSION,
+        });
+    }
+
+    let (content, stored) = bytes.split_at(bytes.len() - 4);
+    let stored = u32::from_le_bytes([stored[0], stored[1], stored[2], stored[3]]);
+    let computed = crc32(content);
+    if stored != computed {
+        return Err(PersistError::ChecksumMismatch { stored, computed });
+    }
+
+    if content[6] != kind {
+        return Err(PersistError::WrongKind {
+            found: content[6],
+            expected: kind,
+        });
+    }
+    let length = u32::from_le_bytes([content[7], content[8], content[9], content[10]]) as usize;
+    let payload = &content[HEADER_LEN..];
+    if payload.len() != length {
+        return Err(PersistError::Corrupt(format!(
+            "header says {} payload bytes but there are {}",
+            length,
+            payload.len()
+        )));
+    }
+    Ok(payload.to_vec())
+}
+
+//Reads fixed size values from the front of the payload
+struct PayloadReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> PayloadReader<'a> {
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8], PersistError> {
+        if self.bytes.len() < n {
+            return Err(PersistError::Corrupt(String::from("payload ends too early")));
+        }
+        let (head, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(head)
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], PersistError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.bytes(N)?);
+        Ok(array)
+    }
+
+    fn u32(&mut self) -> Result<u32, PersistError> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
+    fn finish(self) -> Result<(), PersistError> {
+        if self.bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(PersistError::Corrupt(format!("{} unexpected bytes after the items", self.bytes.len())))
+        }
+    }
+}
+
+//CRC-32 (the one zip and PNG use), one bit at a time. Slower than a lookup table but short.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn kind_name(kind: u8) -> &'static str {
+    match kind {
+        1 => "users",
+        2 => "numbers",
+        _ => "unknown data",
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+//std's BinaryHeap can give you the smallest item fast, but once something is inside you can't find it
+//again to change its priority. Dijkstra's shortest path, schedulers with aging and timers that get
+//rescheduled all need that ("decrease-key").
+//
+//The fix is a heap that remembers where every key sits: `heap` is an ordinary binary min-heap stored
+//in a Vec (the children of index i are 2i+1 and 2i+2) and `positions` maps each key to its index.
+//Every swap inside the heap updates both entries in `positions`.
+pub struct IndexedPriorityQueue<K, P> {
+    heap: Vec<(K, P)>,
+    positions: HashMap<K, usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum QueueError {
+    NotFound,
+    AlreadyQueued,
+    NotADecrease, //decrease_key was given a priority that isn't smaller
+}
+
+impl fmt::Display for QueueError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QueueError::NotFound => write!(f, "key is not in the queue"),
+            QueueError::AlreadyQueued => write!(f, "key is already in the queue"),
+            QueueError::NotADecrease => write!(f, "new priority is not smaller than the current one"),
+        }
+    }
+}
+
+impl std::error::Error for QueueError {}
+
+impl<K: Hash + Eq + Clone, P: Ord> IndexedPriorityQueue<K, P> {
+    pub fn new() -> IndexedPriorityQueue<K, P> {
+        IndexedPriorityQueue {
+            heap: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.positions.contains_key(key)
+    }
+
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        self.positions.get(key).map(|&i| &self.heap[i].1)
+    }
+
+    pub fn push(&mut self, key: K, priority: P) -> Result<(), QueueError> {
+        if self.positions.contains_key(&key) {
+            return Err(QueueError::AlreadyQueued);
+        }
+        let index = self.heap.len();
+        self.positions.insert(key.clone(), index);
+        self.heap.push((key, priority));
+        self.sift_up(index);
+        Ok(())
+    }
+
+    //The key with the smallest priority, without removing it
+    pub fn peek(&self) -> Option<(&K, &P)> {
+        self.heap.first().map(|(k, p)| (k, p))
+    }
+
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        Some(self.remove_at(0))
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<P> {
+        let index = *self.positions.get(key)?;
+        Some(self.remove_at(index).1)
+    }
+
+    //Lowers a key's priority, e.g. when Dijkstra finds a shorter path. O(log n).
+    pub fn decrease_key(&mut self, key: &K, priority: P) -> Result<(), QueueError> {
+        let index = *self.positions.get(key).ok_or(QueueError::NotFound)?;
+        if priority >= self.heap[index].1 {
+            return Err(QueueError::NotADecrease);
+        }
+        self.heap[index].1 = priority;
+        self.sift_up(index);
+        Ok(())
+    }
+
+    //Sets a new priority in either direction and returns the old one
+    pub fn change_priority(&mut self, key: &K, priority: P) -> Result<P, QueueError> {
+        let index = *self.positions.get(key).ok_or(QueueError::NotFound)?;
+        let old = std::mem::replace(&mut self.heap[index].1, priority);
+        self.sift_up(index);
+        self.sift_down(index);
+        Ok(old)
+    }
+
+    fn remove_at(&mut self, index: usize) -> (K, P) {
+        let last = self.heap.len() - 1;
+        self.swap(index, last);
+        let (key, priority) = self.heap.pop().unwrap();
+        self.positions.remove(&key);
+        //the item moved into `index` came from the bottom, it may belong higher or lower
+        if index < self.heap.len() {
+            self.sift_up(index);
+            self.sift_down(index);
+        }
+        (key, priority)
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.heap[index].1 >= self.heap[parent].1 {
+                break;
+            }
+            self.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut smallest = index;
+            if left < self.heap.len() && self.heap[left].1 < self.heap[smallest].1 {
+                smallest = left;
+            }
+            if right < self.heap.len() && self.heap[right].1 < self.heap[smallest].1 {
+                smallest = right;
+            }
+            if smallest == index {
+                break;
+            }
+            self.swap(index, smallest);
+            index = smallest;
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        *self.positions.get_mut(&self.heap[a].0).unwrap() = a;
+        *self.positions.get_mut(&self.heap[b].0).unwrap() = b;
+    }
+}
+
+impl<K: Hash + Eq + Clone, P: Ord> Default for IndexedPriorityQueue<K, P> {
+    fn default() -> Self {
+        IndexedPriorityQueue::new()
+    }
+}
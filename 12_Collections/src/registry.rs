@@ -0,0 +1,130 @@
+use crate::trie::Trie;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::RangeBounds;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    pub name: String, //spelled the way it was added
+    pub age: i32,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RegistryError {
+    Duplicate(String), //a user with this name (ignoring case) already exists
+    NotFound(String),
+    InvalidName(String),
+    InvalidAge(i32),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryError::Duplicate(name) => write!(f, "user {} already exists", name),
+            RegistryError::NotFound(name) => write!(f, "no user called {}", name),
+            RegistryError::InvalidName(name) => write!(f, "invalid name {:?}, names must be one word", name),
+            RegistryError::InvalidAge(age) => write!(f, "invalid age {}, ages can't be negative", age),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+//The name -> age HashMap from the lesson, grown into something you could actually use.
+//Names are looked up ignoring case, so the map is keyed by the lowercase name and
+//the User keeps the original spelling for printing.
+#[derive(Default)]
+pub struct UserRegistry {
+    users: HashMap<String, User>,
+    names: Trie<()>, //the same lowercase names, for prefix search
+}
+
+impl UserRegistry {
+    pub fn new() -> UserRegistry {
+        UserRegistry::default()
+    }
+
+    //Builds a registry from saved users, with the same checks as inserting them one by one
+    pub fn from_users(users: impl IntoIterator<Item = User>) -> Result<UserRegistry, RegistryError> {
+        let mut registry = UserRegistry::new();
+        for user in users {
+            registry.insert(&user.name, user.age)?;
+        }
+        Ok(registry)
+    }
+
+    pub fn len(&self) -> usize {
+        self.users.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+    }
+
+    pub fn insert(&mut self, name: &str, age: i32) -> Result<&User, RegistryError> {
+        let key = validate(name, age)?;
+        if self.users.contains_key(&key) {
+            return Err(RegistryError::Duplicate(name.to_string()));
+        }
+        let user = User {
+            name: name.to_string(),
+            age,
+        };
+        self.names.insert(&key, ());
+        Ok(self.users.entry(key).or_insert(user))
+    }
+
+    //Changes the age of an existing user and returns the old one
+    pub fn update(&mut self, name: &str, age: i32) -> Result<i32, RegistryError> {
+        let key = validate(name, age)?;
+        match self.users.get_mut(&key) {
+            Some(user) => Ok(std::mem::replace(&mut user.age, age)),
+            None => Err(RegistryError::NotFound(name.to_string())),
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) -> Result<User, RegistryError> {
+        let key = name.to_lowercase();
+        self.names.remove(&key);
+        self.users
+            .remove(&key)
+            .ok_or_else(|| RegistryError::NotFound(name.to_string()))
+    }
+
+    //Up to `limit` users whose name starts with `prefix` (ignoring case), sorted by name
+    pub fn complete(&self, prefix: &str, limit: usize) -> Vec<&User> {
+        self.names
+            .iter_prefix(&prefix.to_lowercase())
+            .take(limit)
+            .map(|(key, _)| &self.users[&key])
+            .collect()
+    }
+
+    //"ayush", "AYUSH" and "Ayush" all find the same user
+    pub fn lookup(&self, name: &str) -> Option<&User> {
+        self.users.get(&name.to_lowercase())
+    }
+
+    //Users whose age is in `ages` (e.g. 18..30 or 21..), youngest first, ties sorted by name
+    pub fn in_age_range(&self, ages: impl RangeBounds<i32>) -> Vec<&User> {
+        let mut found: Vec<&User> = self.users.values().filter(|u| ages.contains(&u.age)).collect();
+        found.sort_by(|a, b| a.age.cmp(&b.age).then_with(|| a.name.cmp(&b.name)));
+        found
+    }
+
+    pub fn all(&self) -> Vec<&User> {
+        self.in_age_range(..)
+    }
+}
+
+//Returns the lowercase key for a valid name
+fn validate(name: &str, age: i32) -> Result<String, RegistryError> {
+    if name.is_empty() || name.chars().any(char::is_whitespace) {
+        return Err(RegistryError::InvalidName(name.to_string()));
+    }
+    if age < 0 {
+        return Err(RegistryError::InvalidAge(age));
+    }
+    Ok(name.to_lowercase())
+}
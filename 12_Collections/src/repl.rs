@@ -0,0 +1,132 @@
+use crate::persist::Persist;
+use crate::registry::{User, UserRegistry};
+use bootcamp_core::input;
+use bootcamp_core::output::Table;
+use bootcamp_core::{BootcampError, Result};
+use std::fmt::Display;
+use std::io::{BufRead, Write};
+use std::ops::Bound;
+
+const HELP: &str = "\
+Commands:
+  add <name> <age>       add a new user
+  update <name> <age>    change a user's age
+  remove <name>          delete a user
+  find <name>            look a user up (case doesn't matter)
+  complete <prefix>      names starting with <prefix>
+  list [--older-than N] [--younger-than N]
+  save <file>            save the users, .json and .csv files are text, anything else is binary
+  load <file>            replace the users with the ones saved in <file>
+  help                   show this message
+  quit                   leave";
+
+//Reads commands from `input` until `quit` or end of input. Mistakes are reported and the REPL carries on,
+//only I/O errors end it early.
+pub fn run(registry: &mut UserRegistry, input: &mut impl BufRead, out: &mut impl Write) -> Result<()> {
+    writeln!(out, "User registry, type `help` for the commands")?;
+    loop {
+        write!(out, "> ")?;
+        out.flush()?;
+        let Some(line) = input::read_line(input)? else {
+            writeln!(out)?;
+            return Ok(());
+        };
+        match execute(registry, &line, out) {
+            Ok(true) => {}
+            Ok(false) => return Ok(()),
+            Err(BootcampError::Io(e)) => return Err(BootcampError::Io(e)),
+            Err(BootcampError::Usage(message)) => writeln!(out, "Error: {}", message)?,
+            Err(e) => writeln!(out, "Error: {}", e)?,
+        }
+    }
+}
+
+//Runs a single command. Returns Ok(false) when the user asked to quit.
+pub fn execute(registry: &mut UserRegistry, line: &str, out: &mut impl Write) -> Result<bool> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    match words.as_slice() {
+        [] => {}
+        ["add", name, age] => {
+            let user = registry.insert(name, input::parse(age)?).map_err(reported)?;
+            writeln!(out, "Added {} ({})", user.name, user.age)?;
+        }
+        ["update", name, age] => {
+            let new_age = input::parse(age)?;
+            let old_age = registry.update(name, new_age).map_err(reported)?;
+            let name = registry.lookup(name).map_or(*name, |u| u.name.as_str());
+            writeln!(out, "Updated {}: {} -> {}", name, old_age, new_age)?;
+        }
+        ["remove", name] => {
+            let user = registry.remove(name).map_err(reported)?;
+            writeln!(out, "Removed {}", user.name)?;
+        }
+        ["find", name] => match registry.lookup(name) {
+            Some(user) => writeln!(out, "{} is {}", user.name, user.age)?,
+            None => writeln!(out, "No user called {}", name)?,
+        },
+        ["complete", prefix] => {
+            let names: Vec<&str> = registry.complete(prefix, 10).iter().map(|u| u.name.as_str()).collect();
+            if names.is_empty() {
+                writeln!(out, "No names start with {}", prefix)?;
+            } else {
+                writeln!(out, "{}", names.join(" "))?;
+            }
+        }
+        ["list", options @ ..] => {
+            let users = registry.in_age_range(age_bounds(options)?);
+            print_users(&users, out)?;
+        }
+        ["save", path] => {
+            registry.save_to_file(path).map_err(reported)?;
+            writeln!(out, "Saved {} users to {}", registry.len(), path)?;
+        }
+        ["load", path] => {
+            *registry = UserRegistry::load_from_file(path).map_err(reported)?;
+            writeln!(out, "Loaded {} users from {}", registry.len(), path)?;
+        }
+        ["help"] => writeln!(out, "{}", HELP)?,
+        ["quit"] | ["exit"] => return Ok(false),
+        _ => {
+            return Err(BootcampError::Usage(format!(
+                "unknown command {:?}, type `help` for the commands",
+                line.trim()
+            )));
+        }
+    }
+    Ok(true)
+}
+
+//Turns `--older-than 20 --younger-than 30` into (Excluded(20), Excluded(30))
+fn age_bounds(options: &[&str]) -> Result<(Bound<i32>, Bound<i32>)> {
+    let mut bounds = (Bound::Unbounded, Bound::Unbounded);
+    for pair in options.chunks(2) {
+        match pair {
+            ["--older-than", age] => bounds.0 = Bound::Excluded(input::parse(age)?),
+            ["--younger-than", age] => bounds.1 = Bound::Excluded(input::parse(age)?),
+            _ => {
+                return Err(BootcampError::Usage(String::from(
+                    "usage: list [--older-than N] [--younger-than N]",
+                )));
+            }
+        }
+    }
+    Ok(bounds)
+}
+
+fn print_users(users: &[&User], out: &mut impl Write) -> Result<()> {
+    if users.is_empty() {
+        writeln!(out, "No users")?;
+        return Ok(());
+    }
+    let mut table = Table::new(&["Name", "Age"]);
+    for user in users {
+        table.row(&[user.name.clone(), user.age.to_string()]);
+    }
+    table.write_to(out)?;
+    Ok(())
+}
+
+//Registry and file errors are reported like any other bad command instead of ending the REPL
+fn reported(e: impl Display) -> BootcampError {
+    BootcampError::Usage(e.to_string())
+}
@@ -0,0 +1,173 @@
+use bootcamp_core::output::Table;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::io::{self, Write};
+
+//A single CPU running jobs one after another, to compare how the order we pick jobs in
+//changes how long they wait. Time is in made-up "ticks", nothing actually sleeps.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Job {
+    pub id: u32,
+    pub arrival: u64,  //tick the job shows up
+    pub duration: u64, //ticks it needs once started
+    pub priority: u32, //lower is more urgent
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Policy {
+    Fifo,             //first come, first served
+    Priority,         //most urgent first
+    ShortestJobFirst, //quickest job first
+}
+
+impl Policy {
+    pub const ALL: [Policy; 3] = [Policy::Fifo, Policy::Priority, Policy::ShortestJobFirst];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Policy::Fifo => "FIFO",
+            Policy::Priority => "Priority",
+            Policy::ShortestJobFirst => "Shortest job first",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Finished {
+    pub id: u32,
+    pub start: u64,
+    pub end: u64,
+    pub wait: u64, //start - arrival
+}
+
+pub struct Report {
+    pub policy: Policy,
+    pub finished: Vec<Finished>, //in the order the jobs ran
+}
+
+impl Report {
+    pub fn average_wait(&self) -> f64 {
+        if self.finished.is_empty() {
+            return 0.0;
+        }
+        self.finished.iter().map(|f| f.wait).sum::<u64>() as f64 / self.finished.len() as f64
+    }
+
+    pub fn max_wait(&self) -> u64 {
+        self.finished.iter().map(|f| f.wait).max().unwrap_or(0)
+    }
+
+    pub fn order(&self) -> Vec<u32> {
+        self.finished.iter().map(|f| f.id).collect()
+    }
+}
+
+//The jobs that have arrived but not started yet. FIFO only needs a queue, the other two
+//need whichever job is "smallest" by some key, which is what a BinaryHeap is for.
+//BinaryHeap always pops the largest item, so keys are wrapped in Reverse to get the smallest.
+//Arrival and id are part of every key so ties are broken the same way every run.
+enum ReadyQueue {
+    Fifo(VecDeque<Job>),
+    Heap(BinaryHeap<Reverse<(SortKey, Job)>>), //ids are unique, so the key alone decides the order
+}
+
+//(priority or duration, arrival, id)
+type SortKey = (u64, u64, u32);
+
+impl ReadyQueue {
+    fn new(policy: Policy) -> ReadyQueue {
+        match policy {
+            Policy::Fifo => ReadyQueue::Fifo(VecDeque::new()),
+            _ => ReadyQueue::Heap(BinaryHeap::new()),
+        }
+    }
+
+    fn push(&mut self, policy: Policy, job: Job) {
+        match self {
+            ReadyQueue::Fifo(queue) => queue.push_back(job),
+            ReadyQueue::Heap(heap) => {
+                let first = match policy {
+                    Policy::Priority => job.priority as u64,
+                    _ => job.duration,
+                };
+                let key = (first, job.arrival, job.id);
+                heap.push(Reverse((key, job)));
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<Job> {
+        match self {
+            ReadyQueue::Fifo(queue) => queue.pop_front(),
+            ReadyQueue::Heap(heap) => heap.pop().map(|Reverse((_, job))| job),
+        }
+    }
+}
+
+//Runs every job to completion, one at a time. A job that is running is never interrupted.
+pub fn simulate(jobs: &[Job], policy: Policy) -> Report {
+    let mut pending: Vec<Job> = jobs.to_vec();
+    pending.sort_by_key(|j| (j.arrival, j.id));
+    let mut pending = pending.into_iter().peekable();
+
+    let mut ready = ReadyQueue::new(policy);
+    let mut now = 0;
+    let mut finished = Vec::with_capacity(jobs.len());
+    loop {
+        while let Some(job) = pending.next_if(|j| j.arrival <= now) {
+            ready.push(policy, job);
+        }
+        match ready.pop() {
+            Some(job) => {
+                let start = now;
+                now += job.duration;
+                finished.push(Finished {
+                    id: job.id,
+                    start,
+                    end: now,
+                    wait: start - job.arrival,
+                });
+            }
+            //the CPU is idle, skip ahead to the next arrival
+            None => match pending.peek() {
+                Some(next) => now = next.arrival,
+                None => break,
+            },
+        }
+    }
+    Report { policy, finished }
+}
+
+pub fn compare(jobs: &[Job], out: &mut impl Write) -> io::Result<()> {
+    let mut table = Table::new(&["Policy", "Avg wait", "Max wait", "Order"]);
+    for policy in Policy::ALL {
+        let report = simulate(jobs, policy);
+        let order: Vec<String> = report.order().iter().map(|id| id.to_string()).collect();
+        table.row(&[
+            policy.name().to_string(),
+            format!("{:.2}", report.average_wait()),
+            report.max_wait().to_string(),
+            order.join(" "),
+        ]);
+    }
+    table.write_to(out)
+}
+
+//The job list for the demo: one long low-priority job arrives first and blocks everyone under FIFO
+pub fn sample_jobs() -> Vec<Job> {
+    let job = |id, arrival, duration, priority| Job {
+        id,
+        arrival,
+        duration,
+        priority,
+    };
+    vec![
+        job(1, 0, 10, 3),
+        job(2, 1, 2, 1),
+        job(3, 2, 1, 2),
+        job(4, 3, 6, 1),
+        job(5, 4, 3, 3),
+        job(6, 20, 4, 2),
+        job(7, 21, 1, 1),
+    ]
+}
@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+//Two structures that answer questions about huge streams in a small fixed amount of memory,
+//by allowing a (configurable) amount of error:
+//  BloomFilter    - "have I seen this before?" Never says no for something it saw,
+//                   sometimes says yes for something it didn't (a false positive).
+//  CountMinSketch - "how many times have I seen this?" Never undercounts, sometimes overcounts.
+//
+//Both are saved with serde and can be merged, e.g. one filter per worker thread combined at the end.
+
+#[derive(Debug, PartialEq)]
+pub enum SketchError {
+    ShapeMismatch, //merging two sketches that were created with different sizes
+    Corrupt(String),
+}
+
+impl fmt::Display for SketchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SketchError::ShapeMismatch => write!(f, "can only merge sketches created with the same parameters"),
+            SketchError::Corrupt(message) => write!(f, "corrupt sketch: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SketchError {}
+
+//std's HashMap seeds its hasher randomly every run, which would make a saved filter useless
+//in the next run. FNV-1a has no seed, so the same item always lands on the same bits.
+struct Fnv1a(u64);
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= b as u64;
+            self.0 = self.0.wrapping_mul(0x100_0000_01b3);
+        }
+    }
+}
+
+//Two independent-looking 64 bit hashes of an item. The i-th of k hash functions is then h1 + i*h2
+//(Kirsch and Mitzenmacher showed that's as good as k separate hash functions).
+fn hash_pair<T: Hash + ?Sized>(item: &T) -> (u64, u64) {
+    let mut hasher = Fnv1a(0xcbf2_9ce4_8422_2325);
+    item.hash(&mut hasher);
+    let h = hasher.finish();
+    (mix(h), mix(h ^ 0x9E37_79B9_7F4A_7C15) | 1) //odd, so the steps never get stuck on one slot
+}
+
+//SplitMix64's finaliser, spreads FNV's weaker low bits over the whole word
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+fn nth_hash((h1, h2): (u64, u64), i: usize, buckets: usize) -> usize {
+    (h1.wrapping_add((i as u64).wrapping_mul(h2)) % buckets as u64) as usize
+}
+
+//A row of bits. Inserting sets k bits chosen by hashing the item, a lookup checks if all k are set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "BloomFilterData")]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: usize,
+    num_hashes: usize,
+    inserted: u64, //how many inserts happened, for estimating the current false positive rate
+}
+
+//What's on disk, checked before it becomes a BloomFilter
+#[derive(Deserialize)]
+struct BloomFilterData {
+    bits: Vec<u64>,
+    num_bits: usize,
+    num_hashes: usize,
+    inserted: u64,
+}
+
+impl TryFrom<BloomFilterData> for BloomFilter {
+    type Error = SketchError;
+
+    fn try_from(data: BloomFilterData) -> Result<Self, SketchError> {
+        if data.num_bits == 0 || data.num_hashes == 0 || data.bits.len() != data.num_bits.div_ceil(64) {
+            return Err(SketchError::Corrupt(String::from("bit count doesn't match the stored bits")));
+        }
+        Ok(BloomFilter {
+            bits: data.bits,
+            num_bits: data.num_bits,
+            num_hashes: data.num_hashes,
+            inserted: data.inserted,
+        })
+    }
+}
+
+impl BloomFilter {
+    //Sized so that after `expected_items` inserts about `false_positive_rate` of lookups for
+    //items that were never inserted still say yes. The standard formulas:
+    //  bits   m = -n * ln(p) / ln(2)^2
+    //  hashes k = m / n * ln(2)
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> BloomFilter {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "false positive rate must be between 0 and 1"
+        );
+        let n = expected_items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = (-n * false_positive_rate.ln() / (ln2 * ln2)).ceil().max(64.0) as usize;
+        let num_hashes = ((num_bits as f64 / n) * ln2).round().max(1.0) as usize;
+        BloomFilter::with_size(num_bits, num_hashes)
+    }
+
+    pub fn with_size(num_bits: usize, num_hashes: usize) -> BloomFilter {
+        assert!(num_bits > 0 && num_hashes > 0, "a bloom filter needs at least one bit and one hash");
+        BloomFilter {
+            bits: vec![0; num_bits.div_ceil(64)],
+            num_bits,
+            num_hashes,
+            inserted: 0,
+        }
+    }
+
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    pub fn num_hashes(&self) -> usize {
+        self.num_hashes
+    }
+
+    //Returns true if the item was (probably) new, handy for deduplicating: `if filter.insert(x) { ... }`
+    pub fn insert<T: Hash + ?Sized>(&mut self, item: &T) -> bool {
+        let hashes = hash_pair(item);
+        let mut new = false;
+        for i in 0..self.num_hashes {
+            let bit = nth_hash(hashes, i, self.num_bits);
+            let (word, mask) = (bit / 64, 1u64 << (bit % 64));
+            new |= self.bits[word] & mask == 0;
+            self.bits[word] |= mask;
+        }
+        self.inserted += 1;
+        new
+    }
+
+    //false means definitely never inserted, true means probably inserted
+    pub fn contains<T: Hash + ?Sized>(&self, item: &T) -> bool {
+        let hashes = hash_pair(item);
+        (0..self.num_hashes).all(|i| {
+            let bit = nth_hash(hashes, i, self.num_bits);
+            self.bits[bit / 64] & (1 << (bit % 64)) != 0
+        })
+    }
+
+    //(1 - e^(-k*n/m))^k, the chance a never-inserted item looks present right now
+    pub fn estimated_false_positive_rate(&self) -> f64 {
+        let k = self.num_hashes as f64;
+        let fill = 1.0 - (-k * self.inserted as f64 / self.num_bits as f64).exp();
+        fill.powf(k)
+    }
+
+    //Afterwards this filter contains everything either filter contained
+    pub fn merge(&mut self, other: &BloomFilter) -> Result<(), SketchError> {
+        if self.num_bits != other.num_bits || self.num_hashes != other.num_hashes {
+            return Err(SketchError::ShapeMismatch);
+        }
+        for (a, b) in self.bits.iter_mut().zip(&other.bits) {
+            *a |= b;
+        }
+        self.inserted += other.inserted;
+        Ok(())
+    }
+}
+
+//`depth` rows of `width` counters. Adding an item bumps one counter per row (each row hashes
+//differently), the estimate is the smallest of those counters. Other items that collide only ever
+//add to a counter, so the estimate can be too high but never too low.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "CountMinSketchData")]
+pub struct CountMinSketch {
+    counters: Vec<u64>, //row after row
+    width: usize,
+    depth: usize,
+    total: u64,
+}
+
+#[derive(Deserialize)]
+struct CountMinSketchData {
+    counters: Vec<u64>,
+    width: usize,
+    depth: usize,
+    total: u64,
+}
+
+impl TryFrom<CountMinSketchData> for CountMinSketch {
+    type Error = SketchError;
+
+    fn try_from(data: CountMinSketchData) -> Result<Self, SketchError> {
+        if data.width == 0 || data.depth == 0 || Some(data.counters.len()) != data.width.checked_mul(data.depth) {
+            return Err(SketchError::Corrupt(String::from("counter count doesn't match width * depth")));
+        }
+        Ok(CountMinSketch {
+            counters: data.counters,
+            width: data.width,
+            depth: data.depth,
+            total: data.total,
+        })
+    }
+}
+
+impl CountMinSketch {
+    //With probability 1 - `delta`, an estimate is at most `epsilon` * (total of all counts) too high.
+    //  width = e / epsilon, depth = ln(1 / delta)
+    pub fn new(epsilon: f64, delta: f64) -> CountMinSketch {
+        assert!(epsilon > 0.0 && delta > 0.0 && delta < 1.0, "epsilon must be positive and delta between 0 and 1");
+        let width = (std::f64::consts::E / epsilon).ceil() as usize;
+        let depth = (1.0 / delta).ln().ceil().max(1.0) as usize;
+        CountMinSketch::with_size(width, depth)
+    }
+
+    pub fn with_size(width: usize, depth: usize) -> CountMinSketch {
+        assert!(width > 0 && depth > 0, "a count-min sketch needs at least one row and column");
+        CountMinSketch {
+            counters: vec![0; width * depth],
+            width,
+            depth,
+            total: 0,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    //Sum of every count added so far
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn add<T: Hash + ?Sized>(&mut self, item: &T, count: u64) {
+        let hashes = hash_pair(item);
+        for row in 0..self.depth {
+            let index = row * self.width + nth_hash(hashes, row, self.width);
+            self.counters[index] = self.counters[index].saturating_add(count);
+        }
+        self.total = self.total.saturating_add(count);
+    }
+
+    pub fn estimate<T: Hash + ?Sized>(&self, item: &T) -> u64 {
+        let hashes = hash_pair(item);
+        (0..self.depth)
+            .map(|row| self.counters[row * self.width + nth_hash(hashes, row, self.width)])
+            .min()
+            .unwrap_or(0)
+    }
+
+    //Afterwards the estimates are as if every add to `other` had been made here too
+    pub fn merge(&mut self, other: &CountMinSketch) -> Result<(), SketchError> {
+        if self.width != other.width || self.depth != other.depth {
+            return Err(SketchError::ShapeMismatch);
+        }
+        for (a, b) in self.counters.iter_mut().zip(&other.counters) {
+            *a = a.saturating_add(*b);
+        }
+        self.total = self.total.saturating_add(other.total);
+        Ok(())
+    }
+}
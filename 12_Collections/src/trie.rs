@@ -0,0 +1,204 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+
+//A map from strings to values that can also answer "which keys start with ...?".
+//
+//Every key is spelled out as a path from the root, one character per level, so keys that share a
+//prefix share the nodes for it:
+//
+//    root -a- * -y- * -u- * -s- * -h- [Ayush]
+//                           \-a- * -n- [Ayan]
+//
+//Finding everything that starts with "ay" means walking down two levels and listing the subtree.
+//Children are kept in a BTreeMap, so walking them in order gives keys in sorted order for free.
+pub struct Trie<V> {
+    root: Node<V>,
+    len: usize,
+}
+
+struct Node<V> {
+    value: Option<V>, //Some if a key ends here
+    children: BTreeMap<char, Node<V>>,
+}
+
+impl<V> Node<V> {
+    fn new() -> Node<V> {
+        Node {
+            value: None,
+            children: BTreeMap::new(),
+        }
+    }
+}
+
+impl<V> Trie<V> {
+    pub fn new() -> Trie<V> {
+        Trie {
+            root: Node::new(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    //Inserts or replaces, returning the old value
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.entry(c).or_insert_with(Node::new);
+        }
+        let old = node.value.replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.node(key)?.value.as_ref()
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.get_mut(&c)?;
+        }
+        node.value.as_mut()
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    //Removes a key and prunes the nodes that only existed for it, so the trie doesn't keep
+    //empty branches around after lots of removals
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        let chars: Vec<char> = key.chars().collect();
+        let removed = remove_from(&mut self.root, &chars);
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    //Every key starting with `prefix`, in sorted order
+    pub fn iter_prefix<'a>(&'a self, prefix: &str) -> impl Iterator<Item = (String, &'a V)> + 'a {
+        //depth first with our own stack. Children are pushed in reverse so the smallest comes out first,
+        //and a node's own value is yielded before its children because "ab" sorts before "abc".
+        let mut stack: Vec<(String, &Node<V>)> = self.node(prefix).map(|n| (prefix.to_string(), n)).into_iter().collect();
+        std::iter::from_fn(move || {
+            while let Some((key, node)) = stack.pop() {
+                for (c, child) in node.children.iter().rev() {
+                    let mut child_key = key.clone();
+                    child_key.push(*c);
+                    stack.push((child_key, child));
+                }
+                if let Some(value) = &node.value {
+                    return Some((key, value));
+                }
+            }
+            None
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (String, &V)> {
+        self.iter_prefix("")
+    }
+
+    //The `limit` keys starting with `prefix` that have the highest score, best first.
+    //Equal scores are sorted by key. Only `limit` candidates are kept in memory at any time:
+    //a min-heap holds the best ones so far and the worst of them is dropped when a better one shows up.
+    pub fn autocomplete<S: Ord>(&self, prefix: &str, limit: usize, score: impl Fn(&V) -> S) -> Vec<(String, &V)> {
+        if limit == 0 {
+            return Vec::new();
+        }
+        //Reverse makes BinaryHeap (a max-heap) pop the worst candidate first.
+        //Within equal scores the alphabetically later key counts as worse.
+        let mut best = BinaryHeap::new();
+        for (key, value) in self.iter_prefix(prefix) {
+            best.push(Reverse((score(value), Reverse(key), Unordered(value))));
+            if best.len() > limit {
+                best.pop();
+            }
+        }
+        //into_sorted_vec sorts ascending, which for Reverse means best first
+        best.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((_, Reverse(key), Unordered(value)))| (key, value))
+            .collect()
+    }
+
+    //Nodes including the root, to check that removal really prunes
+    pub fn node_count(&self) -> usize {
+        fn count<V>(node: &Node<V>) -> usize {
+            1 + node.children.values().map(count).sum::<usize>()
+        }
+        count(&self.root)
+    }
+
+    fn node(&self, key: &str) -> Option<&Node<V>> {
+        let mut node = &self.root;
+        for c in key.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+}
+
+impl<V> Default for Trie<V> {
+    fn default() -> Self {
+        Trie::new()
+    }
+}
+
+impl<'k, V> FromIterator<(&'k str, V)> for Trie<V> {
+    fn from_iter<I: IntoIterator<Item = (&'k str, V)>>(iter: I) -> Self {
+        let mut trie = Trie::new();
+        for (key, value) in iter {
+            trie.insert(key, value);
+        }
+        trie
+    }
+}
+
+fn remove_from<V>(node: &mut Node<V>, key: &[char]) -> Option<V> {
+    let Some((first, rest)) = key.split_first() else {
+        return node.value.take();
+    };
+    let child = node.children.get_mut(first)?;
+    let removed = remove_from(child, rest);
+    //a child with no value and no children of its own leads nowhere, cut it off
+    if removed.is_some() && child.value.is_none() && child.children.is_empty() {
+        node.children.remove(first);
+    }
+    removed
+}
+
+//Lets values that aren't Ord sit in the heap. Keys are unique, so the (score, key) part of the
+//tuple always decides the order before this is compared.
+struct Unordered<'a, V>(&'a V);
+
+impl<V> PartialEq for Unordered<'_, V> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<V> Eq for Unordered<'_, V> {}
+
+impl<V> PartialOrd for Unordered<'_, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V> Ord for Unordered<'_, V> {
+    fn cmp(&self, _: &Self) -> std::cmp::Ordering {
+        std::cmp::Ordering::Equal
+    }
+}
@@ -0,0 +1,148 @@
+use bootcamp_core::clock::ManualClock;
+use collections::cache::{Cache, CacheStats, LruCache, SharedCache, TtlCache};
+use std::thread;
+use std::time::Duration;
+
+fn keys<V>(cache: &LruCache<&'static str, V>) -> Vec<&'static str> {
+    cache.iter().map(|(k, _)| *k).collect()
+}
+
+#[test]
+fn evicts_least_recently_used() {
+    let mut cache = LruCache::new(3);
+    cache.put("a", 1);
+    cache.put("b", 2);
+    cache.put("c", 3);
+    assert_eq!(keys(&cache), ["c", "b", "a"]);
+
+    assert_eq!(cache.get(&"a"), Some(&1)); //a is now the most recent, b is next to go
+    cache.put("d", 4);
+    assert_eq!(keys(&cache), ["d", "a", "c"]);
+    assert!(!cache.contains(&"b"));
+
+    assert_eq!(cache.put("c", 30), Some(3)); //replacing counts as a use too
+    cache.put("e", 5);
+    assert_eq!(keys(&cache), ["e", "c", "d"]);
+    assert_eq!(cache.len(), 3);
+}
+
+#[test]
+fn peek_does_not_change_the_order() {
+    let mut cache = LruCache::new(2);
+    cache.put("a", 1);
+    cache.put("b", 2);
+    assert_eq!(cache.peek(&"a"), Some(&1));
+    cache.put("c", 3);
+    assert_eq!(keys(&cache), ["c", "b"]);
+}
+
+#[test]
+fn removed_slots_are_reused() {
+    let mut cache = LruCache::new(2);
+    for round in 0..100 {
+        cache.put("x", round);
+        cache.put("y", round);
+        assert_eq!(cache.remove(&"x"), Some(round));
+        assert_eq!(keys(&cache), ["y"]);
+    }
+    cache.put("z", 0);
+    assert_eq!(keys(&cache), ["z", "y"]);
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn counts_hits_misses_and_evictions() {
+    let mut cache = LruCache::new(1);
+    cache.put(1, "one");
+    cache.get(&1);
+    cache.get(&2);
+    cache.put(2, "two");
+    let stats = cache.stats();
+    assert_eq!(
+        stats,
+        CacheStats {
+            hits: 1,
+            misses: 1,
+            evictions: 1,
+            expirations: 0
+        }
+    );
+    assert_eq!(stats.hit_rate(), 0.5);
+}
+
+#[test]
+fn ttl_entries_expire() {
+    let clock = ManualClock::new();
+    let mut cache = TtlCache::with_clock(10, Duration::from_secs(60), &clock);
+    cache.put("session", 1);
+    cache.put_with_ttl("token", 2, Duration::from_secs(5));
+
+    clock.advance(Duration::from_secs(4));
+    assert_eq!(cache.get(&"token"), Some(&2));
+    assert_eq!(cache.time_left(&"token"), Some(Duration::from_secs(1)));
+
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(cache.get(&"token"), None);
+    assert_eq!(cache.get(&"session"), Some(&1));
+
+    clock.advance(Duration::from_secs(60));
+    cache.put("fresh", 3);
+    assert_eq!(cache.purge_expired(), 1);
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.stats().expirations, 2);
+    assert_eq!(cache.stats().misses, 1);
+}
+
+#[test]
+fn ttl_too_long_to_count_never_expires() {
+    let clock = ManualClock::new();
+    let mut cache = TtlCache::with_clock(10, Duration::MAX, &clock);
+    cache.put("forever", 1);
+    cache.put_with_ttl("also forever", 2, Duration::MAX);
+    assert_eq!(cache.time_left(&"forever"), Some(Duration::MAX));
+
+    clock.advance(Duration::from_secs(100 * 365 * 24 * 60 * 60));
+    assert_eq!(cache.purge_expired(), 0);
+    assert_eq!(cache.get(&"forever"), Some(&1));
+    assert_eq!(cache.get(&"also forever"), Some(&2));
+}
+
+#[test]
+fn ttl_cache_still_evicts_by_recency() {
+    let clock = ManualClock::new();
+    let mut cache = TtlCache::with_clock(2, Duration::from_secs(60), &clock);
+    cache.put("a", 1);
+    cache.put("b", 2);
+    cache.get(&"a");
+    cache.put("c", 3);
+    assert_eq!(cache.get(&"b"), None);
+    assert_eq!(cache.get(&"a"), Some(&1));
+    assert_eq!(cache.stats().evictions, 1);
+}
+
+#[test]
+fn shared_cache_works_across_threads() {
+    let cache = SharedCache::new(LruCache::new(100));
+    let handles: Vec<_> = (0..4)
+        .map(|t| {
+            let cache = cache.clone();
+            thread::spawn(move || {
+                for i in 0..50u64 {
+                    let square = cache.get_or_insert_with(i, || i * i);
+                    assert_eq!(square, i * i);
+                    cache.put(1000 + t, t);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(cache.get(&7), Some(49));
+    let stats = cache.stats();
+    assert_eq!(stats.misses, 50); //each square was computed once, whichever thread got there first
+    assert_eq!(stats.hits, 4 * 50 - 50 + 1);
+    assert_eq!(cache.with_lock(|c| c.len()), 54);
+}
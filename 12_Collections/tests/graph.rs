@@ -0,0 +1,105 @@
+use collections::graph::{Graph, GraphError, NodeId};
+
+fn ids(values: &[usize]) -> Vec<NodeId> {
+    values.iter().map(|&i| NodeId(i)).collect()
+}
+
+//    0 -> 1 -> 3
+//    |    ^
+//    v    |
+//    2 ---+    4 (on its own)
+fn small_directed() -> Graph<char, u32> {
+    let mut graph = Graph::directed();
+    let n: Vec<NodeId> = "abcde".chars().map(|c| graph.add_node(c)).collect();
+    graph.add_edge(n[0], n[1], 1);
+    graph.add_edge(n[0], n[2], 1);
+    graph.add_edge(n[2], n[1], 1);
+    graph.add_edge(n[1], n[3], 1);
+    graph
+}
+
+#[test]
+fn bfs_and_dfs_orders() {
+    let graph = small_directed();
+    assert_eq!(graph.bfs(NodeId(0)), ids(&[0, 1, 2, 3]));
+    assert_eq!(graph.dfs(NodeId(0)), ids(&[0, 1, 3, 2]));
+    assert_eq!(graph.bfs(NodeId(4)), ids(&[4]));
+    assert_eq!(graph.bfs(NodeId(3)), ids(&[3])); //edges only go one way
+}
+
+#[test]
+fn topological_sort_puts_dependencies_first() {
+    let graph = small_directed();
+    let order = graph.topological_sort().unwrap();
+    let position = |n: usize| order.iter().position(|&x| x == NodeId(n)).unwrap();
+    assert_eq!(order.len(), 5);
+    for from in graph.node_ids() {
+        for (to, _) in graph.neighbors(from) {
+            assert!(position(from.0) < position(to.0), "{:?} -> {:?}", from, to);
+        }
+    }
+}
+
+#[test]
+fn topological_sort_reports_the_cycle() {
+    let mut graph = small_directed();
+    graph.add_edge(NodeId(3), NodeId(2), 1);
+    match graph.topological_sort() {
+        Err(GraphError::Cycle(cycle)) => assert_eq!(cycle, ids(&[1, 3, 2, 1])),
+        other => panic!("expected a cycle, got {:?}", other.map(|_| ())),
+    }
+
+    let undirected: Graph<(), ()> = Graph::undirected();
+    assert!(matches!(undirected.topological_sort(), Err(GraphError::NotDirected)));
+}
+
+#[test]
+fn connected_components_ignore_direction() {
+    let graph = small_directed();
+    assert_eq!(graph.connected_components(), [ids(&[0, 1, 2, 3]), ids(&[4])]);
+}
+
+#[test]
+fn loads_an_edge_list_and_finds_shortest_roads() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/graphs/roads.txt");
+    let graph = Graph::load_edge_list(path, false).unwrap();
+    let town = |name: &str| graph.find(&name.to_string()).unwrap();
+    assert_eq!(graph.node_count(), 8);
+
+    let paths = graph.dijkstra(town("Ashford"));
+    assert_eq!(paths.distance(town("Epsom")), Some(20));
+    let route: Vec<&str> = paths.path(town("Epsom")).unwrap().iter().map(|&n| graph.node(n).as_str()).collect();
+    assert_eq!(route, ["Ashford", "Camden", "Fulham", "Epsom"]);
+    assert_eq!(paths.distance(town("Dover")), Some(20));
+    assert_eq!(paths.distance(town("Sark")), None);
+    assert_eq!(paths.path(town("Sark")), None);
+    assert_eq!(paths.path(town("Ashford")), Some(vec![town("Ashford")]));
+
+    let components = graph.connected_components();
+    assert_eq!(components.len(), 2);
+    assert_eq!(components[1], [town("Guernsey"), town("Sark")]);
+}
+
+#[test]
+fn dijkstra_skips_paths_too_long_to_add_up() {
+    let mut graph = Graph::directed();
+    let [a, b, c, d] = ['a', 'b', 'c', 'd'].map(|n| graph.add_node(n));
+    graph.add_edge(a, b, u64::MAX - 1);
+    graph.add_edge(b, c, 5);
+    graph.add_edge(a, d, 10);
+    graph.add_edge(d, c, 10);
+    graph.add_edge(b, b, u64::MAX);
+
+    let paths = graph.dijkstra(a);
+    assert_eq!(paths.distance(b), Some(u64::MAX - 1));
+    assert_eq!(paths.distance(c), Some(20));
+    assert_eq!(paths.path(c), Some(vec![a, d, c]));
+}
+
+#[test]
+fn parse_errors_point_at_the_line() {
+    let err = Graph::parse_edge_list("a b 1\n\na b c d\n", true).err().unwrap();
+    assert!(matches!(err, GraphError::Parse { line: 3, .. }), "{}", err);
+    let err = Graph::parse_edge_list("# weights must be numbers\na b far\n", true).err().unwrap();
+    assert_eq!(err.to_string(), "line 2: invalid weight \"far\": invalid digit found in string");
+}
@@ -0,0 +1,74 @@
+use collections::grouping::{count_by, filter_even, group_by_key, index_by, partition_by};
+use proptest::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+#[test]
+fn examples() {
+    assert_eq!(filter_even(vec![1, 2, 3, 4, 5, 6]), vec![2, 4, 6]);
+    assert_eq!(filter_even([-4i64, -3, 0, 7]), vec![-4, 0]);
+    assert_eq!(filter_even([i8::MIN, -1, 6, i8::MAX]), vec![i8::MIN, 6]);
+    assert_eq!(filter_even([u8::MAX, 254, 0]), vec![254, 0]);
+
+    let groups: BTreeMap<_, _> = group_by_key(vec![("a", 1), ("b", 2), ("a", 3)]);
+    assert_eq!(groups, BTreeMap::from([("a", vec![1, 3]), ("b", vec![2])]));
+
+    let lengths: HashMap<_, _> = count_by(["one", "two", "three"], |w| w.len());
+    assert_eq!(lengths, HashMap::from([(3, 2), (5, 1)]));
+
+    let parity: BTreeMap<_, _> = partition_by(1..=5, |x| x % 2 == 0);
+    assert_eq!(parity, BTreeMap::from([(false, vec![1, 3, 5]), (true, vec![2, 4])]));
+
+    let by_first: HashMap<_, _> = index_by(["apple", "avocado", "banana"], |w| w.chars().next());
+    assert_eq!(by_first, HashMap::from([(Some('a'), "avocado"), (Some('b'), "banana")]));
+}
+
+proptest! {
+    #[test]
+    fn filter_even_keeps_exactly_the_even_numbers(v in proptest::collection::vec(any::<i32>(), 0..100)) {
+        let evens = filter_even(v.clone());
+        prop_assert!(evens.iter().all(|x| x % 2 == 0));
+        prop_assert_eq!(evens.len(), v.iter().filter(|x| *x % 2 == 0).count());
+    }
+
+    #[test]
+    fn grouping_keeps_every_value_in_order(pairs in proptest::collection::vec((0u8..10, any::<i32>()), 0..100)) {
+        let groups: HashMap<u8, Vec<i32>> = group_by_key(pairs.clone());
+        prop_assert_eq!(groups.values().map(Vec::len).sum::<usize>(), pairs.len());
+        for (key, values) in &groups {
+            let expected: Vec<i32> = pairs.iter().filter(|(k, _)| k == key).map(|(_, v)| *v).collect();
+            prop_assert_eq!(values, &expected);
+        }
+    }
+
+    #[test]
+    fn hash_map_and_btree_map_agree(pairs in proptest::collection::vec((0u8..10, any::<i32>()), 0..100)) {
+        let hashed: HashMap<u8, Vec<i32>> = group_by_key(pairs.clone());
+        let sorted: BTreeMap<u8, Vec<i32>> = group_by_key(pairs);
+        prop_assert_eq!(hashed.len(), sorted.len());
+        for (key, values) in &sorted {
+            prop_assert_eq!(Some(values), hashed.get(key));
+        }
+        prop_assert!(sorted.keys().zip(sorted.keys().skip(1)).all(|(a, b)| a < b));
+    }
+
+    #[test]
+    fn counts_match_group_sizes(items in proptest::collection::vec(any::<u16>(), 0..100)) {
+        let counts: BTreeMap<u16, usize> = count_by(items.clone(), |x| x % 7);
+        let groups: BTreeMap<u16, Vec<u16>> = partition_by(items.clone(), |x| x % 7);
+        prop_assert_eq!(counts.values().sum::<usize>(), items.len());
+        for (key, count) in &counts {
+            prop_assert_eq!(*count, groups[key].len());
+            prop_assert!(groups[key].iter().all(|x| x % 7 == *key));
+        }
+    }
+
+    #[test]
+    fn index_keeps_the_last_item_per_key(items in proptest::collection::vec(any::<i32>(), 0..100)) {
+        let index: HashMap<i32, i32> = index_by(items.clone(), |x| x.rem_euclid(5));
+        for (key, item) in &index {
+            let last = items.iter().rev().find(|x| x.rem_euclid(5) == *key);
+            prop_assert_eq!(Some(item), last);
+        }
+        prop_assert_eq!(index.len(), count_by::<_, _, HashMap<i32, usize>>(items, |x| x.rem_euclid(5)).len());
+    }
+}
@@ -0,0 +1,155 @@
+//Small sizes, no proptest and no file system access, so these also run under Miri:
+//    cargo +nightly miri test -p collections --test my_vec
+use collections::my_vec::{Growth, MyVec};
+use std::cell::Cell;
+use std::ops::Bound;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+//Counts how many times it was dropped, to catch double drops and leaks
+struct Counted(Rc<Cell<usize>>);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn push_pop_insert_remove() {
+    let mut v = MyVec::new();
+    assert_eq!(v.capacity(), 0);
+    for i in 0..10 {
+        v.push(i);
+    }
+    assert_eq!(&v[..], &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(v.capacity(), 16);
+
+    v.insert(0, 100);
+    v.insert(11, 200);
+    v.insert(5, 300);
+    assert_eq!(&v[..], &[100, 0, 1, 2, 3, 300, 4, 5, 6, 7, 8, 9, 200]);
+
+    assert_eq!(v.remove(0), 100);
+    assert_eq!(v.remove(4), 300);
+    assert_eq!(v.remove(v.len() - 1), 200);
+    assert_eq!(v.pop(), Some(9));
+    assert_eq!(&v[..], &[0, 1, 2, 3, 4, 5, 6, 7, 8]);
+
+    v.sort_by(|a, b| b.cmp(a)); //slice methods come through Deref
+    assert_eq!(v.first(), Some(&8));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn remove_out_of_bounds_panics() {
+    let mut v: MyVec<i32> = MyVec::new();
+    v.remove(0);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn drain_past_the_end_panics() {
+    let mut v: MyVec<i32> = (0..5).collect();
+    v.drain(2..10);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn drain_up_to_usize_max_panics() {
+    let mut v: MyVec<i32> = (0..5).collect();
+    v.drain(..=usize::MAX);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn drain_after_usize_max_panics() {
+    let mut v: MyVec<i32> = (0..5).collect();
+    v.drain((Bound::Excluded(usize::MAX), Bound::Unbounded));
+}
+
+#[test]
+fn drain_yields_the_range_and_closes_the_gap() {
+    let mut v: MyVec<String> = (0..8).map(|i| i.to_string()).collect();
+    let drained: Vec<String> = v.drain(2..5).collect();
+    assert_eq!(drained, ["2", "3", "4"]);
+    assert_eq!(&v[..], &["0", "1", "5", "6", "7"]);
+
+    //only take part of it, the rest is dropped with the Drain
+    let mut drain = v.drain(1..);
+    assert_eq!(drain.next_back(), Some(String::from("7")));
+    assert_eq!(drain.len(), 3);
+    drop(drain);
+    assert_eq!(&v[..], &["0"]);
+}
+
+#[test]
+fn every_element_is_dropped_exactly_once() {
+    let drops = Rc::new(Cell::new(0));
+    let mut v = MyVec::new();
+    for _ in 0..20 {
+        v.push(Counted(Rc::clone(&drops)));
+    }
+    drop(v.pop());
+    drop(v.remove(3));
+    assert_eq!(drops.get(), 2);
+
+    let mut drain = v.drain(5..10);
+    drop(drain.next());
+    drop(drain); //4 left in the range
+    assert_eq!(drops.get(), 7);
+    assert_eq!(v.len(), 13);
+    drop(v);
+    assert_eq!(drops.get(), 20);
+}
+
+#[test]
+fn leaking_a_drain_forgets_the_rest_of_the_vector() {
+    let mut v: MyVec<i32> = (0..10).collect();
+    std::mem::forget(v.drain(2..4)); //safe, just loses everything from index 2 on
+    assert_eq!(&v[..], &[0, 1]);
+    v.push(2);
+    assert_eq!(&v[..], &[0, 1, 2]);
+}
+
+#[test]
+fn zero_sized_types_never_allocate() {
+    let mut v = MyVec::new();
+    for _ in 0..1000 {
+        v.push(());
+    }
+    assert_eq!(v.len(), 1000);
+    assert_eq!(v.capacity(), usize::MAX);
+    v.insert(10, ());
+    v.remove(0);
+    assert_eq!(v.drain(..500).count(), 500);
+    assert_eq!(v.pop(), Some(()));
+    assert_eq!(v.len(), 499);
+}
+
+#[test]
+fn instrumented_mode_reports_every_reallocation() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&log);
+    let mut v = MyVec::new().on_grow(move |g| sink.lock().unwrap().push(g));
+    for i in 0..20u64 {
+        v.push(i);
+    }
+
+    let log = log.lock().unwrap();
+    let caps: Vec<(usize, usize, usize)> = log.iter().map(|g| (g.len, g.old_capacity, g.new_capacity)).collect();
+    assert_eq!(caps, [(0, 0, 4), (4, 4, 8), (8, 8, 16), (16, 16, 32)]);
+    assert!(matches!(log[0], Growth { moved: false, .. })); //a first allocation has nothing to move
+}
+
+#[test]
+fn with_capacity_avoids_reallocation() {
+    let grew = Arc::new(Mutex::new(0));
+    let counter = Arc::clone(&grew);
+    let mut v = MyVec::with_capacity(100).on_grow(move |_| *counter.lock().unwrap() += 1);
+    v.extend(0..100);
+    assert_eq!(*grew.lock().unwrap(), 0);
+    v.push(100);
+    assert_eq!(*grew.lock().unwrap(), 1);
+    assert_eq!(v.capacity(), 200);
+}
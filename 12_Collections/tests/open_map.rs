@@ -0,0 +1,137 @@
+use collections::open_map::OpenMap;
+use proptest::prelude::*;
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+
+//Every key hashes to the same value, so every entry collides. Slow, but a good stress test for probing.
+#[derive(Default)]
+struct Collide;
+
+impl Hasher for Collide {
+    fn finish(&self) -> u64 {
+        42
+    }
+    fn write(&mut self, _: &[u8]) {}
+}
+
+#[test]
+fn basic_operations() {
+    let mut map = OpenMap::new();
+    assert_eq!(map.insert(String::from("Ayush"), 21), None);
+    assert_eq!(map.insert(String::from("John"), 22), None);
+    assert_eq!(map.insert(String::from("Ayush"), 22), Some(21));
+    assert_eq!(map.get("Ayush"), Some(&22)); //&str lookups on String keys, thanks to Borrow
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.remove("John"), Some(22));
+    assert_eq!(map.remove("John"), None);
+    assert!(!map.contains_key("John"));
+    *map.get_mut("Ayush").unwrap() += 1;
+    assert_eq!(map.get("Ayush"), Some(&23));
+}
+
+#[test]
+fn entry_api() {
+    let mut counts: OpenMap<&str, usize> = OpenMap::new();
+    for word in "the cat and the hat and the bat".split(' ') {
+        *counts.entry(word).or_default() += 1;
+    }
+    assert_eq!(counts.get("the"), Some(&3));
+    assert_eq!(counts.get("and"), Some(&2));
+
+    counts.entry("cat").and_modify(|c| *c *= 10).or_insert(0);
+    counts.entry("dog").and_modify(|c| *c *= 10).or_insert(7);
+    assert_eq!(counts.get("cat"), Some(&10));
+    assert_eq!(counts.get("dog"), Some(&7));
+
+    if let collections::open_map::Entry::Occupied(entry) = counts.entry("hat") {
+        assert_eq!(entry.remove(), 1);
+    }
+    assert!(!counts.contains_key("hat"));
+}
+
+#[test]
+fn existing_keys_never_resize() {
+    let mut map = OpenMap::new();
+    let mut i = 0;
+    while map.len() < map.capacity() || map.is_empty() {
+        map.insert(i, 0);
+        i += 1;
+    }
+    let full = map.capacity();
+    for _ in 0..10 {
+        *map.entry(0).or_default() += 1;
+        map.insert(1, 5);
+        assert!(matches!(map.entry(2), collections::open_map::Entry::Occupied(_)));
+    }
+    assert_eq!(map.capacity(), full);
+    assert_eq!(map.get(&0), Some(&10));
+
+    //the next new key does need room
+    map.entry(i).or_insert(1);
+    assert!(map.capacity() > full);
+}
+
+#[test]
+fn grows_and_keeps_everything() {
+    let mut map = OpenMap::new();
+    for i in 0..10_000 {
+        map.insert(i, i * 2);
+    }
+    assert_eq!(map.len(), 10_000);
+    assert!(map.load_factor() <= 0.875);
+    assert!((0..10_000).all(|i| map.get(&i) == Some(&(i * 2))));
+
+    let mut seen: Vec<i32> = map.keys().copied().collect();
+    seen.sort();
+    assert_eq!(seen, (0..10_000).collect::<Vec<_>>());
+}
+
+#[test]
+fn full_collisions_with_a_custom_hasher() {
+    let mut map: OpenMap<u32, u32, BuildHasherDefault<Collide>> = OpenMap::with_hasher(BuildHasherDefault::default());
+    for i in 0..200 {
+        map.insert(i, i);
+    }
+    for i in (0..200).step_by(2) {
+        assert_eq!(map.remove(&i), Some(i));
+    }
+    assert!((0..200).all(|i| map.get(&i) == if i % 2 == 0 { None } else { Some(&i) }));
+    assert_eq!(map.len(), 100);
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    Insert(u8, u32),
+    Remove(u8),
+    Get(u8),
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        (any::<u8>(), any::<u32>()).prop_map(|(k, v)| Op::Insert(k, v)),
+        any::<u8>().prop_map(Op::Remove),
+        any::<u8>().prop_map(Op::Get),
+    ]
+}
+
+proptest! {
+    //Whatever happens, OpenMap must answer exactly like std's HashMap
+    #[test]
+    fn behaves_like_std_hash_map(ops in proptest::collection::vec(op(), 0..500)) {
+        let mut ours = OpenMap::new();
+        let mut std_map = HashMap::new();
+        for op in ops {
+            match op {
+                Op::Insert(k, v) => prop_assert_eq!(ours.insert(k, v), std_map.insert(k, v)),
+                Op::Remove(k) => prop_assert_eq!(ours.remove(&k), std_map.remove(&k)),
+                Op::Get(k) => prop_assert_eq!(ours.get(&k), std_map.get(&k)),
+            }
+            prop_assert_eq!(ours.len(), std_map.len());
+        }
+        let mut entries: Vec<(u8, u32)> = ours.iter().map(|(k, v)| (*k, *v)).collect();
+        let mut expected: Vec<(u8, u32)> = std_map.into_iter().collect();
+        entries.sort();
+        expected.sort();
+        prop_assert_eq!(entries, expected);
+    }
+}
@@ -0,0 +1,108 @@
+use collections::persist::{FORMAT_VERSION, Format, Persist, PersistError};
+use collections::registry::UserRegistry;
+use proptest::prelude::*;
+
+const FORMATS: [Format; 3] = [Format::Json, Format::Csv, Format::Binary];
+
+fn sample() -> UserRegistry {
+    let mut registry = UserRegistry::new();
+    registry.insert("Ayush", 21).unwrap();
+    registry.insert("John", 22).unwrap();
+    registry.insert("Zoë", 0).unwrap();
+    registry
+}
+
+fn round_trip<T: Persist>(value: &T, format: Format) -> Result<T, PersistError> {
+    let mut bytes = Vec::new();
+    value.save(format, &mut bytes)?;
+    T::load(format, &mut bytes.as_slice())
+}
+
+fn binary<T: Persist>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    value.save(Format::Binary, &mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn users_round_trip() {
+    let registry = sample();
+    for format in FORMATS {
+        let loaded = round_trip(&registry, format).unwrap();
+        assert_eq!(loaded.all(), registry.all(), "{:?}", format);
+    }
+}
+
+#[test]
+fn files_pick_the_format_from_the_extension() {
+    let dir = std::env::temp_dir();
+    for name in ["users.json", "users.csv", "users.bin"] {
+        let path = dir.join(format!("collections_{}_{}", std::process::id(), name));
+        sample().save_to_file(&path).unwrap();
+        let loaded = UserRegistry::load_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.all(), sample().all());
+    }
+}
+
+#[test]
+fn newer_format_version_is_rejected() {
+    let mut bytes = binary(&vec![1, 2, 3]);
+    bytes[4..6].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+    let err = Vec::<i32>::load(Format::Binary, &mut bytes.as_slice()).unwrap_err();
+    assert!(matches!(err, PersistError::UnsupportedVersion { found, supported }
+        if found == FORMAT_VERSION + 1 && supported == FORMAT_VERSION));
+    assert!(err.to_string().contains("newer version"));
+}
+
+#[test]
+fn damaged_files_are_rejected() {
+    let mut bytes = binary(&vec![1, 2, 3]);
+    let last_item = bytes.len() - 5;
+    bytes[last_item] ^= 0xFF;
+    assert!(matches!(
+        Vec::<i32>::load(Format::Binary, &mut bytes.as_slice()),
+        Err(PersistError::ChecksumMismatch { .. })
+    ));
+
+    assert!(matches!(
+        Vec::<i32>::load(Format::Binary, &mut &b"{\"not\": \"binary\"}"[..]),
+        Err(PersistError::NotOurFormat)
+    ));
+
+    let users = binary(&sample());
+    assert!(matches!(
+        Vec::<i32>::load(Format::Binary, &mut users.as_slice()),
+        Err(PersistError::WrongKind { found: 1, expected: 2 })
+    ));
+}
+
+#[test]
+fn duplicate_users_in_a_file_are_rejected() {
+    let json = r#"[{"name": "Ayush", "age": 21}, {"name": "AYUSH", "age": 30}]"#;
+    assert!(matches!(
+        UserRegistry::load(Format::Json, &mut json.as_bytes()),
+        Err(PersistError::Invalid(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn numbers_round_trip(numbers in proptest::collection::vec(any::<i32>(), 0..200)) {
+        for format in FORMATS {
+            prop_assert_eq!(&round_trip(&numbers, format).unwrap(), &numbers);
+        }
+    }
+
+    #[test]
+    fn generated_users_round_trip(users in proptest::collection::btree_map("[a-zA-Z]{1,12}", 0i32..120, 0..30)) {
+        let registry = UserRegistry::from_users(users.into_iter().map(|(name, age)| collections::registry::User { name, age }));
+        //random names can clash once case is ignored, those registries are skipped
+        if let Ok(registry) = registry {
+            for format in FORMATS {
+                let loaded = round_trip(&registry, format).unwrap();
+                prop_assert_eq!(loaded.all(), registry.all());
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use collections::priority_queue::{IndexedPriorityQueue, QueueError};
+use proptest::prelude::*;
+
+#[test]
+fn pops_smallest_first() {
+    let mut queue = IndexedPriorityQueue::new();
+    for (key, priority) in [("c", 3), ("a", 1), ("d", 4), ("b", 2)] {
+        queue.push(key, priority).unwrap();
+    }
+    assert_eq!(queue.push("a", 0), Err(QueueError::AlreadyQueued));
+    assert_eq!(queue.peek(), Some((&"a", &1)));
+
+    let order: Vec<&str> = std::iter::from_fn(|| queue.pop().map(|(k, _)| k)).collect();
+    assert_eq!(order, ["a", "b", "c", "d"]);
+}
+
+#[test]
+fn priorities_can_change() {
+    let mut queue = IndexedPriorityQueue::new();
+    for (key, priority) in [("a", 10), ("b", 20), ("c", 30)] {
+        queue.push(key, priority).unwrap();
+    }
+    queue.decrease_key(&"c", 5).unwrap();
+    assert_eq!(queue.peek(), Some((&"c", &5)));
+    assert_eq!(queue.decrease_key(&"c", 6), Err(QueueError::NotADecrease));
+    assert_eq!(queue.decrease_key(&"x", 1), Err(QueueError::NotFound));
+
+    assert_eq!(queue.change_priority(&"c", 50), Ok(5));
+    assert_eq!(queue.remove(&"a"), Some(10));
+    assert_eq!(queue.priority(&"c"), Some(&50));
+    assert_eq!(queue.pop(), Some(("b", 20)));
+    assert_eq!(queue.pop(), Some(("c", 50)));
+    assert!(queue.is_empty());
+}
+
+//The textbook use of decrease-key
+#[test]
+fn dijkstra_shortest_paths() {
+    let edges: &[(usize, usize, u32)] = &[(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (2, 3, 5), (3, 4, 3)];
+    let mut dist = [u32::MAX; 5];
+    let mut queue = IndexedPriorityQueue::new();
+    dist[0] = 0;
+    queue.push(0, 0).unwrap();
+    while let Some((node, d)) = queue.pop() {
+        for &(_, to, weight) in edges.iter().filter(|e| e.0 == node) {
+            let candidate = d + weight;
+            if candidate < dist[to] {
+                dist[to] = candidate;
+                if queue.contains(&to) {
+                    queue.decrease_key(&to, candidate).unwrap();
+                } else {
+                    queue.push(to, candidate).unwrap();
+                }
+            }
+        }
+    }
+    assert_eq!(dist, [0, 3, 1, 4, 7]);
+}
+
+proptest! {
+    //Whatever we push, change and remove, popping everything gives the priorities in sorted order
+    #[test]
+    fn always_pops_in_order(
+        priorities in proptest::collection::vec(any::<i16>(), 0..100),
+        changes in proptest::collection::vec((any::<usize>(), any::<i16>()), 0..50),
+        removals in proptest::collection::vec(any::<usize>(), 0..20),
+    ) {
+        let mut queue = IndexedPriorityQueue::new();
+        let mut expected: Vec<Option<i16>> = priorities.iter().copied().map(Some).collect();
+        for (key, &p) in priorities.iter().enumerate() {
+            queue.push(key, p).unwrap();
+        }
+        if !priorities.is_empty() {
+            for (key, p) in changes {
+                let key = key % priorities.len();
+                if queue.change_priority(&key, p).is_ok() {
+                    expected[key] = Some(p);
+                }
+            }
+            for key in removals {
+                let key = key % priorities.len();
+                prop_assert_eq!(queue.remove(&key), expected[key].take());
+            }
+        }
+
+        let mut expected: Vec<i16> = expected.into_iter().flatten().collect();
+        expected.sort();
+        let popped: Vec<i16> = std::iter::from_fn(|| queue.pop().map(|(_, p)| p)).collect();
+        prop_assert_eq!(popped, expected);
+    }
+}
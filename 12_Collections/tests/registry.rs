@@ -0,0 +1,71 @@
+use collections::registry::{RegistryError, UserRegistry};
+use std::io::Cursor;
+
+fn sample() -> UserRegistry {
+    let mut registry = UserRegistry::new();
+    registry.insert("Ayush", 21).unwrap();
+    registry.insert("John", 22).unwrap();
+    registry.insert("Maya", 35).unwrap();
+    registry
+}
+
+#[test]
+fn names_ignore_case() {
+    let mut registry = sample();
+    assert_eq!(registry.lookup("aYUSH").unwrap().name, "Ayush");
+    assert_eq!(registry.insert("JOHN", 40), Err(RegistryError::Duplicate(String::from("JOHN"))));
+    assert_eq!(registry.update("maya", 36), Ok(35));
+    assert_eq!(registry.remove("MAYA").unwrap().age, 36);
+    assert_eq!(registry.len(), 2);
+}
+
+#[test]
+fn invalid_input_is_rejected() {
+    let mut registry = sample();
+    assert_eq!(registry.insert("", 1), Err(RegistryError::InvalidName(String::new())));
+    assert_eq!(registry.insert("Two words", 1), Err(RegistryError::InvalidName(String::from("Two words"))));
+    assert_eq!(registry.insert("Kid", -1), Err(RegistryError::InvalidAge(-1)));
+    assert_eq!(registry.update("Nobody", 1), Err(RegistryError::NotFound(String::from("Nobody"))));
+    assert_eq!(registry.remove("Nobody"), Err(RegistryError::NotFound(String::from("Nobody"))));
+    assert_eq!(registry.len(), 3);
+}
+
+#[test]
+fn age_ranges() {
+    let registry = sample();
+    let names = |users: Vec<&collections::registry::User>| users.iter().map(|u| u.name.clone()).collect::<Vec<_>>();
+    assert_eq!(names(registry.in_age_range(22..)), ["John", "Maya"]);
+    assert_eq!(names(registry.in_age_range(..=21)), ["Ayush"]);
+    assert_eq!(names(registry.all()), ["Ayush", "John", "Maya"]);
+}
+
+#[test]
+fn name_completion() {
+    let mut registry = sample();
+    registry.insert("Ayan", 30).unwrap();
+    let names = |users: Vec<&collections::registry::User>| users.iter().map(|u| u.name.clone()).collect::<Vec<_>>();
+    assert_eq!(names(registry.complete("AY", 10)), ["Ayan", "Ayush"]);
+    assert_eq!(names(registry.complete("", 2)), ["Ayan", "Ayush"]);
+    registry.remove("ayan").unwrap();
+    assert_eq!(names(registry.complete("ay", 10)), ["Ayush"]);
+}
+
+#[test]
+fn repl_session() {
+    let commands = "add Ayush 21\nadd ayush 30\nadd John 22\nfind JOHN\nlist --older-than 21\nlist --younger-than 22\nlist --older-than 40\nremove ayush\nquit\nadd Ignored 1\n";
+    let mut registry = UserRegistry::new();
+    let mut out = Vec::new();
+    collections::repl::run(&mut registry, &mut Cursor::new(commands), &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+
+    assert!(out.contains("Added Ayush (21)"));
+    assert!(out.contains("Error: user ayush already exists"));
+    assert!(out.contains("John is 22"));
+    //Ayush is 21, so only John is older than 21 and only Ayush younger than 22
+    assert!(out.contains("> Name | Age\n-----+----\nJohn |  22\n>"), "{}", out);
+    assert!(out.contains(">  Name | Age\n------+----\nAyush |  21\n>"), "{}", out);
+    assert!(out.contains("> No users\n"));
+    assert!(out.contains("Removed Ayush"));
+    assert!(registry.lookup("Ignored").is_none());
+    assert_eq!(registry.len(), 1);
+}
@@ -0,0 +1,54 @@
+use collections::scheduler::{Finished, Job, Policy, sample_jobs, simulate};
+
+#[test]
+fn fifo_runs_jobs_in_arrival_order() {
+    let report = simulate(&sample_jobs(), Policy::Fifo);
+    let waits: Vec<u64> = report.finished.iter().map(|f| f.wait).collect();
+    assert_eq!(report.order(), [1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(waits, [0, 9, 10, 10, 15, 2, 5]);
+    assert_eq!(report.max_wait(), 15);
+    assert!((report.average_wait() - 51.0 / 7.0).abs() < 1e-9);
+}
+
+#[test]
+fn priority_and_shortest_job_first_reorder_the_waiting_jobs() {
+    assert_eq!(simulate(&sample_jobs(), Policy::Priority).order(), [1, 2, 4, 3, 5, 7, 6]);
+    assert_eq!(simulate(&sample_jobs(), Policy::ShortestJobFirst).order(), [1, 3, 2, 5, 4, 7, 6]);
+}
+
+#[test]
+fn shortest_job_first_has_the_lowest_average_wait() {
+    let jobs = sample_jobs();
+    let sjf = simulate(&jobs, Policy::ShortestJobFirst).average_wait();
+    for policy in [Policy::Fifo, Policy::Priority] {
+        assert!(sjf <= simulate(&jobs, policy).average_wait(), "{:?}", policy);
+    }
+}
+
+#[test]
+fn idle_cpu_skips_to_the_next_arrival() {
+    let jobs = [
+        Job { id: 1, arrival: 5, duration: 2, priority: 0 },
+        Job { id: 2, arrival: 100, duration: 1, priority: 0 },
+    ];
+    let report = simulate(&jobs, Policy::Priority);
+    assert_eq!(
+        report.finished,
+        [
+            Finished { id: 1, start: 5, end: 7, wait: 0 },
+            Finished { id: 2, start: 100, end: 101, wait: 0 },
+        ]
+    );
+    assert!(simulate(&[], Policy::Fifo).finished.is_empty());
+}
+
+#[test]
+fn ties_are_broken_by_arrival_then_id() {
+    let jobs: Vec<Job> = (1..=4)
+        .rev()
+        .map(|id| Job { id, arrival: 0, duration: 1, priority: 1 })
+        .collect();
+    for policy in Policy::ALL {
+        assert_eq!(simulate(&jobs, policy).order(), [1, 2, 3, 4], "{:?}", policy);
+    }
+}
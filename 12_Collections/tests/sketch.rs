@@ -0,0 +1,124 @@
+use collections::sketch::{BloomFilter, CountMinSketch, SketchError};
+
+//Items that were inserted are "item-<n>", ones that weren't are "other-<n>", so they never overlap
+fn measured_false_positive_rate(filter: &BloomFilter, lookups: usize) -> f64 {
+    let hits = (0..lookups).filter(|i| filter.contains(&format!("other-{}", i))).count();
+    hits as f64 / lookups as f64
+}
+
+#[test]
+fn bloom_filter_has_no_false_negatives() {
+    let mut filter = BloomFilter::new(1000, 0.01);
+    for i in 0..1000 {
+        filter.insert(&format!("item-{}", i));
+    }
+    assert!((0..1000).all(|i| filter.contains(&format!("item-{}", i))));
+}
+
+#[test]
+fn bloom_filter_false_positive_rate_matches_the_target() {
+    for target in [0.1, 0.01, 0.001] {
+        let items = 10_000;
+        let mut filter = BloomFilter::new(items, target);
+        for i in 0..items {
+            filter.insert(&format!("item-{}", i));
+        }
+        let measured = measured_false_positive_rate(&filter, 200_000);
+        //the hashes are fixed, so this is the same number every run; 1.5x leaves room for the
+        //formula being an approximation
+        assert!(measured <= target * 1.5, "target {} measured {}", target, measured);
+        assert!(measured >= target / 3.0, "target {} measured {}, filter is oversized", target, measured);
+        assert!((filter.estimated_false_positive_rate() - target).abs() < target * 0.5);
+    }
+}
+
+#[test]
+fn bloom_filter_sizes_follow_the_formula() {
+    let filter = BloomFilter::new(1_000_000, 0.01);
+    assert_eq!(filter.num_bits(), 9_585_059);
+    assert_eq!(filter.num_hashes(), 7);
+}
+
+#[test]
+fn insert_reports_new_items_for_deduplication() {
+    let mut filter = BloomFilter::new(100, 0.001);
+    let stream = ["a", "b", "a", "c", "b", "a"];
+    let unique: Vec<&str> = stream.iter().copied().filter(|s| filter.insert(s)).collect();
+    assert_eq!(unique, ["a", "b", "c"]);
+}
+
+#[test]
+fn merged_bloom_filters_contain_both_sides() {
+    let mut left = BloomFilter::new(1000, 0.01);
+    let mut right = BloomFilter::new(1000, 0.01);
+    (0..500).for_each(|i| {
+        left.insert(&i);
+    });
+    (500..1000).for_each(|i| {
+        right.insert(&i);
+    });
+    left.merge(&right).unwrap();
+    assert!((0..1000).all(|i| left.contains(&i)));
+    assert_eq!(left.merge(&BloomFilter::new(10, 0.5)), Err(SketchError::ShapeMismatch));
+}
+
+#[test]
+fn bloom_filter_survives_a_json_round_trip() {
+    let mut filter = BloomFilter::new(100, 0.01);
+    filter.insert("saved");
+    let json = serde_json::to_string(&filter).unwrap();
+    let loaded: BloomFilter = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, filter);
+    assert!(loaded.contains("saved"));
+
+    let broken = r#"{"bits": [0], "num_bits": 1000, "num_hashes": 3, "inserted": 0}"#;
+    assert!(serde_json::from_str::<BloomFilter>(broken).is_err());
+}
+
+//A skewed stream: item n shows up (n % 50) + 1 times
+fn skewed_counts() -> Vec<(String, u64)> {
+    (0..5000).map(|n| (format!("item-{}", n), n % 50 + 1)).collect()
+}
+
+#[test]
+fn count_min_never_undercounts_and_rarely_overcounts_much() {
+    let (epsilon, delta) = (0.001, 0.01);
+    let mut sketch = CountMinSketch::new(epsilon, delta);
+    let counts = skewed_counts();
+    for (item, count) in &counts {
+        sketch.add(item, *count);
+    }
+    let total: u64 = counts.iter().map(|(_, c)| c).sum();
+    assert_eq!(sketch.total(), total);
+
+    let allowed = (epsilon * total as f64) as u64;
+    let mut too_high = 0;
+    for (item, count) in &counts {
+        let estimate = sketch.estimate(item);
+        assert!(estimate >= *count, "{} undercounted", item);
+        if estimate - count > allowed {
+            too_high += 1;
+        }
+    }
+    let rate = too_high as f64 / counts.len() as f64;
+    assert!(rate <= delta, "{} of estimates were off by more than epsilon * total", rate);
+    assert!(sketch.estimate("never added") <= allowed);
+}
+
+#[test]
+fn merged_sketches_count_both_streams() {
+    let mut left = CountMinSketch::new(0.01, 0.01);
+    let mut right = CountMinSketch::new(0.01, 0.01);
+    left.add("apple", 3);
+    right.add("apple", 4);
+    right.add("pear", 1);
+    left.merge(&right).unwrap();
+    assert!(left.estimate("apple") >= 7);
+    assert!(left.estimate("pear") >= 1);
+    assert_eq!(left.total(), 8);
+    assert_eq!(left.merge(&CountMinSketch::with_size(3, 3)), Err(SketchError::ShapeMismatch));
+
+    let loaded: CountMinSketch = serde_json::from_str(&serde_json::to_string(&left).unwrap()).unwrap();
+    assert_eq!(loaded, left);
+    assert!(serde_json::from_str::<CountMinSketch>(r#"{"counters": [], "width": 2, "depth": 2, "total": 0}"#).is_err());
+}
@@ -0,0 +1,97 @@
+use collections::trie::Trie;
+use proptest::prelude::*;
+use std::collections::BTreeMap;
+
+fn names() -> Trie<u32> {
+    [("ayush", 21), ("ayan", 30), ("aya", 5), ("john", 22), ("jo", 40), ("joanna", 22)]
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn exact_lookups() {
+    let mut trie = names();
+    assert_eq!(trie.len(), 6);
+    assert_eq!(trie.get("ayush"), Some(&21));
+    assert_eq!(trie.get("ay"), None); //only a prefix, not a key
+    assert_eq!(trie.get("ayushi"), None);
+    assert_eq!(trie.insert("ayush", 22), Some(21));
+    *trie.get_mut("jo").unwrap() += 1;
+    assert_eq!(trie.get("jo"), Some(&41));
+    assert_eq!(trie.len(), 6);
+}
+
+#[test]
+fn prefix_iteration_is_sorted() {
+    let trie = names();
+    let keys: Vec<String> = trie.iter_prefix("ay").map(|(k, _)| k).collect();
+    assert_eq!(keys, ["aya", "ayan", "ayush"]);
+    let keys: Vec<String> = trie.iter_prefix("jo").map(|(k, _)| k).collect();
+    assert_eq!(keys, ["jo", "joanna", "john"]);
+    assert_eq!(trie.iter_prefix("x").count(), 0);
+    assert_eq!(trie.iter().count(), 6);
+}
+
+#[test]
+fn removal_prunes_dead_branches() {
+    let mut trie = Trie::new();
+    trie.insert("car", 1);
+    let just_car = trie.node_count();
+    trie.insert("cartoon", 2);
+    trie.insert("cars", 3);
+
+    assert_eq!(trie.remove("cartoon"), Some(2));
+    assert_eq!(trie.remove("cars"), Some(3));
+    assert_eq!(trie.remove("cars"), None);
+    assert_eq!(trie.node_count(), just_car);
+
+    //removing a key in the middle of a path keeps the longer keys
+    trie.insert("carpet", 4);
+    assert_eq!(trie.remove("car"), Some(1));
+    assert_eq!(trie.get("carpet"), Some(&4));
+    assert_eq!(trie.remove("carpet"), Some(4));
+    assert_eq!(trie.node_count(), 1);
+    assert!(trie.is_empty());
+}
+
+#[test]
+fn autocomplete_returns_the_best_scores() {
+    let trie = names();
+    let top: Vec<(String, u32)> = trie.autocomplete("", 3, |&age| age).into_iter().map(|(k, v)| (k, *v)).collect();
+    //john and joanna tie at 22, only joanna fits and wins alphabetically
+    assert_eq!(top, [(String::from("jo"), 40), (String::from("ayan"), 30), (String::from("joanna"), 22)]);
+
+    let youngest: Vec<String> = trie.autocomplete("ay", 10, |&age| std::cmp::Reverse(age)).into_iter().map(|(k, _)| k).collect();
+    assert_eq!(youngest, ["aya", "ayush", "ayan"]);
+    assert!(trie.autocomplete("ay", 0, |&age| age).is_empty());
+}
+
+proptest! {
+    #[test]
+    fn behaves_like_a_sorted_map(
+        inserts in proptest::collection::vec(("[abc]{0,5}", any::<u8>()), 0..60),
+        removes in proptest::collection::vec("[abc]{0,5}", 0..30),
+        prefix in "[abc]{0,2}",
+    ) {
+        let mut trie = Trie::new();
+        let mut model = BTreeMap::new();
+        for (key, value) in &inserts {
+            prop_assert_eq!(trie.insert(key, *value), model.insert(key.clone(), *value));
+        }
+        for key in &removes {
+            prop_assert_eq!(trie.remove(key), model.remove(key));
+        }
+        prop_assert_eq!(trie.len(), model.len());
+
+        let ours: Vec<(String, u8)> = trie.iter_prefix(&prefix).map(|(k, v)| (k, *v)).collect();
+        let expected: Vec<(String, u8)> = model.iter().filter(|(k, _)| k.starts_with(&prefix)).map(|(k, v)| (k.clone(), *v)).collect();
+        prop_assert_eq!(ours, expected);
+
+        //pruning: every node is on the path of some remaining key
+        let path_nodes: std::collections::BTreeSet<&str> = model
+            .keys()
+            .flat_map(|k| (1..=k.len()).map(move |i| &k[..i]))
+            .collect();
+        prop_assert_eq!(trie.node_count(), 1 + path_nodes.len());
+    }
+}
@@ -0,0 +1,367 @@
+use std::collections::VecDeque;
+use std::iter::{FusedIterator, Peekable};
+
+//`filter` and `map` in the lesson are adapters: they wrap another iterator and change what comes
+//out of it, doing no work until someone calls next(). This trait adds some of our own.
+//Every iterator gets them through the blanket impl at the bottom, just `use iterators::adapters::IterExt`.
+//
+//Each adapter is its own struct holding the inner iterator, like std's Filter and Map, and reports
+//an honest size_hint: the lower bound is never too high and the upper bound is never too low.
+pub trait IterExt: Iterator + Sized {
+    //Vecs of exactly `size` items. Leftovers that don't fill a chunk are kept, see ChunksExact::remainder.
+    fn chunks_exact_by(self, size: usize) -> ChunksExact<Self> {
+        assert!(size > 0, "chunk size must be at least 1");
+        ChunksExact {
+            iter: self,
+            size,
+            remainder: Vec::new(),
+        }
+    }
+
+    //Every run of `size` consecutive items: [1, 2, 3, 4].windows(2) gives [1, 2], [2, 3], [3, 4]
+    fn windows(self, size: usize) -> Windows<Self>
+    where
+        Self::Item: Clone,
+    {
+        assert!(size > 0, "window size must be at least 1");
+        Windows {
+            iter: self,
+            size,
+            window: VecDeque::with_capacity(size),
+            started: false,
+        }
+    }
+
+    //Takes turns: one from self, one from other, ... When one runs out the rest of the other follows.
+    fn interleave<J>(self, other: J) -> Interleave<Self, J::IntoIter>
+    where
+        J: IntoIterator<Item = Self::Item>,
+    {
+        Interleave {
+            a: self,
+            b: other.into_iter(),
+            next_from_b: false,
+        }
+    }
+
+    //Drops items whose key equals the key of the item just before them (like Vec::dedup_by_key)
+    fn dedup_by_key<K, F>(self, key: F) -> DedupByKey<Self, K, F>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        DedupByKey {
+            iter: self,
+            key,
+            last: None,
+        }
+    }
+
+    //Puts a clone of `separator` between every two items.
+    //Nightly Rust has an unstable Iterator::intersperse, so call this one as IterExt::intersperse(iter, sep)
+    //to keep the compiler from warning about the name clash.
+    fn intersperse(self, separator: Self::Item) -> Intersperse<Self>
+    where
+        Self::Item: Clone,
+    {
+        Intersperse {
+            iter: self.peekable(),
+            separator,
+            separator_next: false,
+        }
+    }
+
+    //Hands the inner iterator to `f`, which takes as many items as it likes and returns one batch.
+    //Stops when `f` returns None. E.g. reading length-prefixed records: |it| { let n = it.next()?; Some(it.take(n).collect()) }
+    fn batching<B, F>(self, f: F) -> Batching<Self, F>
+    where
+        F: FnMut(&mut Self) -> Option<B>,
+    {
+        Batching { iter: self, f }
+    }
+
+    //Folds like `fold`, but yields every intermediate state, and stops at the first state that fails
+    //`keep_going`. Running totals below 10: [1, 2, 3, 4, 5].scan_while(0, |sum, x| sum + x, |&sum| sum < 10)
+    //gives 1, 3, 6.
+    fn scan_while<S, F, P>(self, initial: S, f: F, keep_going: P) -> ScanWhile<Self, S, F, P>
+    where
+        S: Clone,
+        F: FnMut(&S, Self::Item) -> S,
+        P: FnMut(&S) -> bool,
+    {
+        ScanWhile {
+            iter: self,
+            state: initial,
+            f,
+            keep_going,
+            done: false,
+        }
+    }
+}
+
+impl<I: Iterator> IterExt for I {}
+
+pub struct ChunksExact<I: Iterator> {
+    iter: I,
+    size: usize,
+    remainder: Vec<I::Item>,
+}
+
+impl<I: Iterator> ChunksExact<I> {
+    //The items at the end that weren't enough for a whole chunk. Only filled in once the
+    //iterator has returned None.
+    pub fn remainder(&self) -> &[I::Item] {
+        &self.remainder
+    }
+}
+
+impl<I: Iterator> Iterator for ChunksExact<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let mut chunk = Vec::with_capacity(self.size);
+        for item in self.iter.by_ref() {
+            chunk.push(item);
+            if chunk.len() == self.size {
+                return Some(chunk);
+            }
+        }
+        if !chunk.is_empty() {
+            self.remainder = chunk;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower / self.size, upper.map(|u| u / self.size))
+    }
+}
+
+pub struct Windows<I: Iterator> {
+    iter: I,
+    size: usize,
+    window: VecDeque<I::Item>,
+    started: bool, //the first full window has been returned
+}
+
+impl<I: Iterator> Iterator for Windows<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.started {
+            let item = self.iter.next()?;
+            self.window.pop_front();
+            self.window.push_back(item);
+        } else {
+            while self.window.len() < self.size {
+                self.window.push_back(self.iter.next()?);
+            }
+            self.started = true;
+        }
+        Some(self.window.iter().cloned().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.started {
+            //one more window per remaining item
+            (lower, upper)
+        } else {
+            //the first window needs `size` items, each one after that gives another window
+            let missing = self.size - 1 - self.window.len().min(self.size - 1);
+            let windows = |n: usize| n.saturating_sub(missing);
+            (windows(lower), upper.map(windows))
+        }
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Windows<I> where I::Item: Clone {}
+
+pub struct Interleave<A, B> {
+    a: A,
+    b: B,
+    next_from_b: bool,
+}
+
+impl<A, B> Iterator for Interleave<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        self.next_from_b = !self.next_from_b;
+        if self.next_from_b {
+            self.a.next().or_else(|| self.b.next())
+        } else {
+            self.b.next().or_else(|| self.a.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lower.saturating_add(b_lower), upper)
+    }
+}
+
+impl<A: FusedIterator, B: FusedIterator<Item = A::Item>> FusedIterator for Interleave<A, B> {}
+
+pub struct DedupByKey<I, K, F> {
+    iter: I,
+    key: F,
+    last: Option<K>,
+}
+
+impl<I, K, F> Iterator for DedupByKey<I, K, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            let item = self.iter.next()?;
+            let key = (self.key)(&item);
+            if self.last.as_ref() != Some(&key) {
+                self.last = Some(key);
+                return Some(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        //before the first item nothing can be a duplicate yet, after that everything might be
+        let lower = if self.last.is_none() { lower.min(1) } else { 0 };
+        (lower, upper)
+    }
+}
+
+impl<I: FusedIterator, K: PartialEq, F: FnMut(&I::Item) -> K> FusedIterator for DedupByKey<I, K, F> {}
+
+pub struct Intersperse<I: Iterator> {
+    iter: Peekable<I>,
+    separator: I::Item,
+    separator_next: bool, //an item was just returned, a separator goes next if more items follow
+}
+
+impl<I: Iterator> Iterator for Intersperse<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.separator_next && self.iter.peek().is_some() {
+            self.separator_next = false;
+            return Some(self.separator.clone());
+        }
+        let item = self.iter.next()?;
+        self.separator_next = true;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        //n items left need n - 1 separators between them, plus one in front if an item came before
+        let total = |n: usize| {
+            if n == 0 {
+                Some(0)
+            } else {
+                n.checked_mul(2).map(|t| if self.separator_next { t } else { t - 1 })
+            }
+        };
+        let (lower, upper) = self.iter.size_hint();
+        (total(lower).unwrap_or(usize::MAX), upper.and_then(total))
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Intersperse<I> where I::Item: Clone {}
+
+pub struct Batching<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, B, F> Iterator for Batching<I, F>
+where
+    I: Iterator,
+    F: FnMut(&mut I) -> Option<B>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        (self.f)(&mut self.iter)
+    }
+
+    //`f` can make a batch out of any number of items, even none, so there's nothing to promise
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+pub struct ScanWhile<I, S, F, P> {
+    iter: I,
+    state: S,
+    f: F,
+    keep_going: P,
+    done: bool,
+}
+
+impl<I, S, F, P> Iterator for ScanWhile<I, S, F, P>
+where
+    I: Iterator,
+    S: Clone,
+    F: FnMut(&S, I::Item) -> S,
+    P: FnMut(&S) -> bool,
+{
+    type Item = S;
+
+    fn next(&mut self) -> Option<S> {
+        if self.done {
+            return None;
+        }
+        let item = match self.iter.next() {
+            Some(item) => item,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        let next = (self.f)(&self.state, item);
+        if !(self.keep_going)(&next) {
+            self.done = true;
+            return None;
+        }
+        self.state = next;
+        Some(self.state.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I, S, F, P> FusedIterator for ScanWhile<I, S, F, P>
+where
+    I: Iterator,
+    S: Clone,
+    F: FnMut(&S, I::Item) -> S,
+    P: FnMut(&S) -> bool,
+{
+}
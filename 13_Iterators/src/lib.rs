@@ -0,0 +1,74 @@
+pub mod adapters;
+pub mod parallel;
+pub mod pipeline;
+pub mod sequences;
+pub mod streaming;
+
+use std::io::{self, Write};
+use std::vec;
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    // let mut v = vec![1, 2, 3, 4, 5];
+    // let mut iter = v.iter_mut();
+
+    // for val in iter{
+    //     *val *= 2; 
+    // }
+
+    // while let Some(val) = iter.next() {
+    //     print!("{} ", val); // Output: 12345
+    // }
+    //whenever .next() is called on an iterator, it starts to consume the values from the iterator and moves the iterator forward. Therefore, it return Option because it may not always have a value to return(the end of the iterator).
+    //src/sequences.rs implements Iterator for a few sequences of our own (Fibonacci, primes, ...), next() is all it takes.
+    // println!("{:?}", v); // Output: [2, 4, 6, 8, 10]
+
+    // let nums = vec![1, 2, 3, 4, 5];
+
+    // for num in nums{ //uses the IntoIterator trait to convert the vector into an iterator
+    //     println!("{}", num); // Output: 12345
+    // }
+
+    // println!("{:?}", nums); //Error: cannot borrow `nums` as immutable because it is also borrowed as mutable
+
+    let v1 = vec![1, 2, 3, 4, 5];
+
+    let v1_iter = v1.iter();
+
+    let sum:i32 = v1_iter.sum();
+    //one core does all the adding here, src/parallel.rs splits the same kind of chain across all of them
+
+    writeln!(out, "Sum: {}", sum)?; // Output: Sum: 15)
+
+    // for i in v1_iter { //ERR: Use of moved value: `v1_iter`
+    //     println!("{}", i); // This will not print anything because the iterator has been consumed
+    // }
+
+    let v1_filter_iter = v1.iter().filter(|x| *x % 2 == 0);
+
+    for x in v1_filter_iter {
+        writeln!(out, "Even Value: {}", x)?;
+    }
+    //Nothing here needs the whole Vec in memory at once, the same chain works on a file line by line (src/streaming.rs)
+    //filter is an adapter: it wraps another iterator and does nothing until next() is called.
+    //src/adapters.rs writes a few more (windows, interleave, intersperse, ...) as an IterExt trait
+    Ok(())
+}
+
+
+//Assignment:
+//Write the logic to first filter all odd values then double aech and create a new vector
+//Solve it in exercises/assignments/13_odd_doubled.rs, `cargo run -p exercises -- watch` checks it every time you save
+//Then try it as a one-liner: cargo run -p iterators -- "filter odd | map *2" 1 2 3 4 5 (see src/pipeline.rs)
+
+pub struct Iterators;
+
+impl bootcamp_core::Lesson for Iterators {
+    fn dir(&self) -> &'static str {
+        "13_Iterators"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
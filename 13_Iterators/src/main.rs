@@ -1,46 +1,86 @@
-use std::vec;
+use bootcamp_core::input;
+use bootcamp_core::output::Table;
+use iterators::parallel::{ParIter, ParSlice};
+use iterators::pipeline::Pipeline;
+use iterators::sequences::Collatz;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
 
+//`cargo run` for the lesson, `cargo run -- "<pipeline>" [numbers...]` to run a pipeline over the
+//numbers (1 to 10 if none are given), e.g. `cargo run -- "filter odd | map *2 | sum"`,
+//`cargo run --release -- bench` to time par_iter against plain iter
 fn main() {
-    // let mut v = vec![1, 2, 3, 4, 5];
-    // let mut iter = v.iter_mut();
-
-    // for val in iter{
-    //     *val *= 2; 
-    // }
-
-    // while let Some(val) = iter.next() {
-    //     print!("{} ", val); // Output: 12345
-    // }
-    //whenever .next() is called on an iterator, it starts to consume the values from the iterator and moves the iterator forward. Therefore, it return Option because it may not always have a value to return(the end of the iterator).
-    // println!("{:?}", v); // Output: [2, 4, 6, 8, 10]
-
-    // let nums = vec![1, 2, 3, 4, 5];
-
-    // for num in nums{ //uses the IntoIterator trait to convert the vector into an iterator
-    //     println!("{}", num); // Output: 12345
-    // }
-
-    // println!("{:?}", nums); //Error: cannot borrow `nums` as immutable because it is also borrowed as mutable
-
-    let v1 = vec![1, 2, 3, 4, 5];
-
-    let v1_iter = v1.iter();
+    let args = input::args();
+    let Some(source) = args.first() else {
+        iterators::run(&mut std::io::stdout()).unwrap();
+        return;
+    };
+    if source == "bench" {
+        bench();
+        return;
+    }
 
-    let sum:i32 = v1_iter.sum();
+    let pipeline = match Pipeline::parse(source) {
+        Ok(pipeline) => pipeline,
+        Err(e) => {
+            eprintln!("{}", e.show(source));
+            std::process::exit(1);
+        }
+    };
+    let numbers: Vec<i64> = if args.len() > 1 {
+        match args[1..].iter().map(|a| input::parse(a)).collect() {
+            Ok(numbers) => numbers,
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        (1..=10).collect()
+    };
+    println!("{}", pipeline.run(numbers));
+}
 
-    println!("Sum: {}", sum); // Output: Sum: 15)
+//Runs `f` a few times and keeps the fastest, the slower runs are mostly noise from the rest of the machine
+fn fastest<T>(mut f: impl FnMut() -> T) -> Duration {
+    (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
 
-    // for i in v1_iter { //ERR: Use of moved value: `v1_iter`
-    //     println!("{}", i); // This will not print anything because the iterator has been consumed
-    // }
+fn bench() {
+    let numbers: Vec<u64> = (1..=2_000_000).collect();
+    let mut table = Table::new(&["Work", "iter", "par_iter", "Speedup"]);
+    let mut add_row = |name: &str, sequential: Duration, parallel: Duration| {
+        let ms = |t: Duration| format!("{:.1} ms", t.as_secs_f64() * 1000.0);
+        let speedup = format!("{:.2}x", sequential.as_secs_f64() / parallel.as_secs_f64());
+        table.row(&[name.to_string(), ms(sequential), ms(parallel), speedup]);
+    };
 
-    let v1_filter_iter = v1.iter().filter(|x| *x % 2 == 0);
+    //cheap work per item: adding up is mostly waiting for memory, threads help less
+    add_row(
+        "filter even, sum",
+        fastest(|| numbers.iter().filter(|x| *x % 2 == 0).sum::<u64>()),
+        fastest(|| numbers.par_iter().filter(|x| *x % 2 == 0).sum::<u64>()),
+    );
+    add_row(
+        "map square, collect",
+        fastest(|| numbers.iter().map(|x| x * x).collect::<Vec<u64>>()),
+        fastest(|| numbers.par_iter().map(|x| x * x).collect::<Vec<u64>>()),
+    );
+    //expensive work per item: every number walks its whole Collatz sequence
+    add_row(
+        "map collatz length, sum",
+        fastest(|| numbers.iter().map(|&n| Collatz::new(n).count()).sum::<usize>()),
+        fastest(|| numbers.par_iter().map(|&n| Collatz::new(n).count()).sum::<usize>()),
+    );
 
-    for x in v1_filter_iter {
-        println!("Even Value: {}", x);
-    }
+    let threads = numbers.par_iter().splitting().threads;
+    println!("{} numbers, {} threads", numbers.len(), threads);
+    table.write_to(&mut std::io::stdout()).unwrap();
 }
-
-
-//Assignment:
-//Write the logic to first filter all odd values then double aech and create a new vector
\ No newline at end of file
@@ -0,0 +1,211 @@
+use std::iter::Sum;
+use std::ops::Range;
+use std::thread;
+
+//The lesson's v1.iter().filter(...).sum() runs on one core. This is a small version of what the
+//rayon crate does: `v1.par_iter().filter(...).sum()` cuts the Vec into one chunk per core, runs
+//the same chain over every chunk on its own thread, and combines the results at the end.
+//
+//Every stage only describes the work (like the lazy adapters in adapters.rs), nothing runs until
+//sum, count or collect. Scoped threads may borrow the Vec, they're all joined before the scope
+//returns. Results are combined in chunk order, so collect keeps the original order.
+
+//How the work gets cut up
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Splitting {
+    pub threads: usize,
+    //Chunks are never smaller than this. Starting a thread costs a few microseconds, for a few
+    //hundred numbers that's more than the work itself.
+    pub min_chunk: usize,
+}
+
+impl Default for Splitting {
+    fn default() -> Self {
+        Splitting {
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+            min_chunk: 1024,
+        }
+    }
+}
+
+//Closures are shared between the threads, so they have to be Fn (no mutable state) and Sync,
+//and items move between threads, so they have to be Send
+pub trait ParIter: Sized + Sync {
+    type Item: Send;
+
+    //How many items the source has, before any filter. That's what gets split.
+    fn source_len(&self) -> usize;
+
+    fn splitting(&self) -> Splitting;
+
+    //The items made from the source items in `range`, as a normal sequential iterator.
+    //Runs on one of the worker threads.
+    fn chunk(&self, range: Range<usize>) -> impl Iterator<Item = Self::Item>;
+
+    fn map<U, F>(self, f: F) -> Map<Self, F>
+    where
+        U: Send,
+        F: Fn(Self::Item) -> U + Sync,
+    {
+        Map { base: self, f }
+    }
+
+    fn filter<F>(self, keep: F) -> Filter<Self, F>
+    where
+        F: Fn(&Self::Item) -> bool + Sync,
+    {
+        Filter { base: self, keep }
+    }
+
+    //Each thread sums its chunk, then the partial sums are added up
+    fn sum<S>(self) -> S
+    where
+        S: Sum<Self::Item> + Sum<S> + Send,
+    {
+        in_chunks(self.source_len(), self.splitting(), |range| self.chunk(range).sum::<S>())
+            .into_iter()
+            .sum()
+    }
+
+    fn count(self) -> usize {
+        in_chunks(self.source_len(), self.splitting(), |range| self.chunk(range).count())
+            .into_iter()
+            .sum()
+    }
+
+    //Each thread collects its chunk into a Vec, the Vecs are then joined in order
+    fn collect<C: FromIterator<Self::Item>>(self) -> C {
+        let chunks = in_chunks(self.source_len(), self.splitting(), |range| self.chunk(range).collect::<Vec<_>>());
+        //appending whole Vecs is a plain memory copy, and turning a Vec's IntoIter back into a Vec
+        //reuses its allocation, so collecting into a Vec costs little more than one copy
+        let mut all = Vec::with_capacity(chunks.iter().map(Vec::len).sum());
+        for chunk in chunks {
+            all.extend(chunk);
+        }
+        all.into_iter().collect()
+    }
+}
+
+//Runs `work` on consecutive ranges covering 0..len, one thread per range, results in range order.
+//A panic on any thread is passed on to the caller.
+fn in_chunks<R, W>(len: usize, splitting: Splitting, work: W) -> Vec<R>
+where
+    R: Send,
+    W: Fn(Range<usize>) -> R + Sync,
+{
+    let chunk_size = len.div_ceil(splitting.threads.max(1)).max(splitting.min_chunk).max(1);
+    if chunk_size >= len {
+        //a single chunk, no point starting a thread for it
+        return vec![work(0..len)];
+    }
+    let work = &work;
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..len)
+            .step_by(chunk_size)
+            .map(|start| scope.spawn(move || work(start..(start + chunk_size).min(len))))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+            .collect()
+    })
+}
+
+//`par_iter()` for slices, and through them Vecs and arrays
+pub trait ParSlice<T: Sync> {
+    fn par_iter(&self) -> Iter<'_, T>;
+}
+
+impl<T: Sync> ParSlice<T> for [T] {
+    fn par_iter(&self) -> Iter<'_, T> {
+        Iter {
+            slice: self,
+            splitting: Splitting::default(),
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    slice: &'a [T],
+    splitting: Splitting,
+}
+
+impl<T> Iter<'_, T> {
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0, "need at least one thread");
+        self.splitting.threads = threads;
+        self
+    }
+
+    pub fn with_min_chunk(mut self, min_chunk: usize) -> Self {
+        self.splitting.min_chunk = min_chunk;
+        self
+    }
+}
+
+impl<'a, T: Sync> ParIter for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn source_len(&self) -> usize {
+        self.slice.len()
+    }
+
+    fn splitting(&self) -> Splitting {
+        self.splitting
+    }
+
+    fn chunk(&self, range: Range<usize>) -> impl Iterator<Item = &'a T> {
+        self.slice[range].iter()
+    }
+}
+
+pub struct Map<P, F> {
+    base: P,
+    f: F,
+}
+
+impl<P, U, F> ParIter for Map<P, F>
+where
+    P: ParIter,
+    U: Send,
+    F: Fn(P::Item) -> U + Sync,
+{
+    type Item = U;
+
+    fn source_len(&self) -> usize {
+        self.base.source_len()
+    }
+
+    fn splitting(&self) -> Splitting {
+        self.base.splitting()
+    }
+
+    fn chunk(&self, range: Range<usize>) -> impl Iterator<Item = U> {
+        self.base.chunk(range).map(&self.f)
+    }
+}
+
+pub struct Filter<P, F> {
+    base: P,
+    keep: F,
+}
+
+impl<P, F> ParIter for Filter<P, F>
+where
+    P: ParIter,
+    F: Fn(&P::Item) -> bool + Sync,
+{
+    type Item = P::Item;
+
+    fn source_len(&self) -> usize {
+        self.base.source_len()
+    }
+
+    fn splitting(&self) -> Splitting {
+        self.base.splitting()
+    }
+
+    fn chunk(&self, range: Range<usize>) -> impl Iterator<Item = P::Item> {
+        self.base.chunk(range).filter(&self.keep)
+    }
+}
@@ -0,0 +1,380 @@
+use crate::adapters::IterExt;
+use std::fmt;
+
+//The lesson's assignment (keep the odd numbers, double them) written as text instead of code:
+//
+//    filter odd | map *2 | take 5 | sum
+//
+//Each `|`-separated stage becomes one iterator adapter, chained in order, just like writing
+//.filter(..).map(..).take(..).sum() by hand. The chain is built at runtime, so every stage is a
+//Box<dyn Iterator>: we don't know the concrete type (Filter<Map<Take<...>>>) until we've parsed the text.
+//
+//Stages:
+//    filter odd | even | positive | negative | > N | >= N | < N | <= N | == N | != N | divisible N
+//    map *N | +N | -N | /N | %N | square | abs | neg
+//    take N, skip N, dedup (drop repeats of the previous value)
+//and optionally one of these at the end (otherwise all values are collected):
+//    sum, product, count, min, max
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pipeline {
+    stages: Vec<Stage>,
+    terminal: Terminal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Stage {
+    Filter(Predicate),
+    Map(Operation),
+    Take(usize),
+    Skip(usize),
+    Dedup,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Predicate {
+    Odd,
+    Even,
+    Compare(Comparison, i64),
+    Divisible(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operation {
+    Multiply(i64),
+    Add(i64),
+    Subtract(i64),
+    Divide(i64),
+    Remainder(i64),
+    Square,
+    Abs,
+    Negate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Terminal {
+    Collect,
+    Sum,
+    Product,
+    Count,
+    Min,
+    Max,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Output {
+    Values(Vec<i64>),
+    Number(i64),
+    Empty, //min or max of no values
+}
+
+impl fmt::Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Output::Values(values) => write!(f, "{:?}", values),
+            Output::Number(n) => write!(f, "{}", n),
+            Output::Empty => write!(f, "(no values)"),
+        }
+    }
+}
+
+//`position` is the byte offset in the pipeline text where the problem starts
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    //The source with a ^ under the problem, like rustc does:
+    //    filter odd | mapp *2
+    //                 ^ unknown stage `mapp`
+    pub fn show(&self, source: &str) -> String {
+        let column = source[..self.position.min(source.len())].chars().count();
+        format!("{}\n{}^ {}", source, " ".repeat(column), self.message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "column {}: {}", self.position + 1, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+//A word of the pipeline text and where it starts
+#[derive(Clone, Copy)]
+struct Word<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+fn error<T>(position: usize, message: impl Into<String>) -> Result<T, ParseError> {
+    Err(ParseError {
+        position,
+        message: message.into(),
+    })
+}
+
+impl Pipeline {
+    pub fn parse(source: &str) -> Result<Pipeline, ParseError> {
+        let mut stages = Vec::new();
+        let mut terminal = None;
+        let mut offset = 0;
+        for part in source.split('|') {
+            let words = split_words(part, offset);
+            let stage_start = offset + (part.len() - part.trim_start().len());
+            offset += part.len() + 1;
+
+            let Some((name, args)) = words.split_first() else {
+                return error(stage_start, "empty stage");
+            };
+            if let Some(t) = terminal {
+                return error(name.position, format!("`{}` has to be the last stage", terminal_name(t)));
+            }
+            match name.text {
+                "sum" | "product" | "count" | "min" | "max" => {
+                    no_arguments(name, args)?;
+                    terminal = Some(match name.text {
+                        "sum" => Terminal::Sum,
+                        "product" => Terminal::Product,
+                        "count" => Terminal::Count,
+                        "min" => Terminal::Min,
+                        _ => Terminal::Max,
+                    });
+                }
+                "filter" => stages.push(Stage::Filter(parse_predicate(name, args)?)),
+                "map" => stages.push(Stage::Map(parse_operation(name, args)?)),
+                "take" => stages.push(Stage::Take(parse_count(name, args)?)),
+                "skip" => stages.push(Stage::Skip(parse_count(name, args)?)),
+                "dedup" => {
+                    no_arguments(name, args)?;
+                    stages.push(Stage::Dedup);
+                }
+                other => return error(name.position, format!("unknown stage `{}`", other)),
+            }
+        }
+        Ok(Pipeline {
+            stages,
+            terminal: terminal.unwrap_or(Terminal::Collect),
+        })
+    }
+
+    //Chains one boxed adapter per stage onto `input`, then consumes it. Nothing is computed
+    //until the terminal step pulls values through, so `take 3` stops the stages before it early.
+    //Arithmetic wraps around on overflow instead of panicking.
+    pub fn run(&self, input: impl IntoIterator<Item = i64> + 'static) -> Output {
+        let mut iter: Box<dyn Iterator<Item = i64>> = Box::new(input.into_iter());
+        for &stage in &self.stages {
+            iter = match stage {
+                Stage::Filter(predicate) => Box::new(iter.filter(move |&x| predicate.test(x))),
+                Stage::Map(operation) => Box::new(iter.map(move |x| operation.apply(x))),
+                Stage::Take(n) => Box::new(iter.take(n)),
+                Stage::Skip(n) => Box::new(iter.skip(n)),
+                Stage::Dedup => Box::new(iter.dedup_by_key(|&x| x)),
+            };
+        }
+        match self.terminal {
+            Terminal::Collect => Output::Values(iter.collect()),
+            Terminal::Sum => Output::Number(iter.fold(0, i64::wrapping_add)),
+            Terminal::Product => Output::Number(iter.fold(1, i64::wrapping_mul)),
+            Terminal::Count => Output::Number(iter.count() as i64),
+            Terminal::Min => iter.min().map_or(Output::Empty, Output::Number),
+            Terminal::Max => iter.max().map_or(Output::Empty, Output::Number),
+        }
+    }
+}
+
+impl Predicate {
+    fn test(self, x: i64) -> bool {
+        match self {
+            Predicate::Odd => x % 2 != 0,
+            Predicate::Even => x % 2 == 0,
+            Predicate::Divisible(n) => x.wrapping_rem(n) == 0,
+            Predicate::Compare(comparison, n) => match comparison {
+                Comparison::Greater => x > n,
+                Comparison::GreaterOrEqual => x >= n,
+                Comparison::Less => x < n,
+                Comparison::LessOrEqual => x <= n,
+                Comparison::Equal => x == n,
+                Comparison::NotEqual => x != n,
+            },
+        }
+    }
+}
+
+impl Operation {
+    fn apply(self, x: i64) -> i64 {
+        match self {
+            Operation::Multiply(n) => x.wrapping_mul(n),
+            Operation::Add(n) => x.wrapping_add(n),
+            Operation::Subtract(n) => x.wrapping_sub(n),
+            Operation::Divide(n) => x.wrapping_div(n),
+            Operation::Remainder(n) => x.wrapping_rem(n),
+            Operation::Square => x.wrapping_mul(x),
+            Operation::Abs => x.wrapping_abs(),
+            Operation::Negate => x.wrapping_neg(),
+        }
+    }
+}
+
+fn terminal_name(terminal: Terminal) -> &'static str {
+    match terminal {
+        Terminal::Collect => "collect",
+        Terminal::Sum => "sum",
+        Terminal::Product => "product",
+        Terminal::Count => "count",
+        Terminal::Min => "min",
+        Terminal::Max => "max",
+    }
+}
+
+fn split_words(part: &str, offset: usize) -> Vec<Word<'_>> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in part.char_indices().chain([(part.len(), ' ')]) {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some(i),
+            (true, Some(s)) => {
+                words.push(Word {
+                    text: &part[s..i],
+                    position: offset + s,
+                });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    words
+}
+
+fn no_arguments(name: &Word, args: &[Word]) -> Result<(), ParseError> {
+    match args.first() {
+        Some(extra) => error(extra.position, format!("`{}` takes no arguments", name.text)),
+        None => Ok(()),
+    }
+}
+
+//Exactly one argument, or an error pointing at what's missing or extra
+fn one_argument<'a>(name: &Word, args: &[Word<'a>], expected: &str) -> Result<Word<'a>, ParseError> {
+    match args {
+        [arg] => Ok(*arg),
+        [] => error(name.position + name.text.len(), format!("`{}` needs {}", name.text, expected)),
+        [_, extra, ..] => error(extra.position, format!("unexpected `{}`", extra.text)),
+    }
+}
+
+fn parse_number(word: Word) -> Result<i64, ParseError> {
+    match word.text.parse() {
+        Ok(n) => Ok(n),
+        Err(_) => error(word.position, format!("expected a number, found `{}`", word.text)),
+    }
+}
+
+fn parse_count(name: &Word, args: &[Word]) -> Result<usize, ParseError> {
+    let word = one_argument(name, args, "a count")?;
+    match word.text.parse() {
+        Ok(n) => Ok(n),
+        Err(_) => error(word.position, format!("expected a count, found `{}`", word.text)),
+    }
+}
+
+//Also accepts the operator and number without a space in between: `> 3` and `>3` are the same
+fn operator_and_number<'a>(args: &[Word<'a>], operators: &[&'a str]) -> Option<(&'a str, Vec<Word<'a>>)> {
+    let first = args.first()?;
+    //longest operators first so `>=` isn't read as `>` followed by `=3`
+    let mut operators = operators.to_vec();
+    operators.sort_by_key(|op| std::cmp::Reverse(op.len()));
+    let op = operators.into_iter().find(|op| first.text.starts_with(op))?;
+    let mut rest = Vec::new();
+    if first.text.len() > op.len() {
+        rest.push(Word {
+            text: &first.text[op.len()..],
+            position: first.position + op.len(),
+        });
+    }
+    rest.extend_from_slice(&args[1..]);
+    Some((op, rest))
+}
+
+fn parse_predicate(name: &Word, args: &[Word]) -> Result<Predicate, ParseError> {
+    if let Some((op, rest)) = operator_and_number(args, &[">", ">=", "<", "<=", "==", "!="]) {
+        let operator = Word {
+            text: op,
+            position: args[0].position,
+        };
+        let n = parse_number(one_argument(&operator, &rest, "a number")?)?;
+        let comparison = match op {
+            ">" => Comparison::Greater,
+            ">=" => Comparison::GreaterOrEqual,
+            "<" => Comparison::Less,
+            "<=" => Comparison::LessOrEqual,
+            "==" => Comparison::Equal,
+            _ => Comparison::NotEqual,
+        };
+        return Ok(Predicate::Compare(comparison, n));
+    }
+
+    let word = args
+        .first()
+        .copied()
+        .ok_or_else(|| ParseError {
+            position: name.position + name.text.len(),
+            message: String::from("`filter` needs a condition like `odd` or `> 3`"),
+        })?;
+    let predicate = match word.text {
+        "odd" => Predicate::Odd,
+        "even" => Predicate::Even,
+        "positive" => Predicate::Compare(Comparison::Greater, 0),
+        "negative" => Predicate::Compare(Comparison::Less, 0),
+        "divisible" => {
+            let n = parse_number(one_argument(&word, &args[1..], "a number")?)?;
+            if n == 0 {
+                return error(args[1].position, "can't divide by zero");
+            }
+            return Ok(Predicate::Divisible(n));
+        }
+        other => return error(word.position, format!("unknown condition `{}`", other)),
+    };
+    no_arguments(&word, &args[1..])?;
+    Ok(predicate)
+}
+
+fn parse_operation(name: &Word, args: &[Word]) -> Result<Operation, ParseError> {
+    if let Some((op, rest)) = operator_and_number(args, &["*", "+", "-", "/", "%"]) {
+        let operator = Word {
+            text: op,
+            position: args[0].position,
+        };
+        let number = one_argument(&operator, &rest, "a number")?;
+        let n = parse_number(number)?;
+        if n == 0 && (op == "/" || op == "%") {
+            return error(number.position, "can't divide by zero");
+        }
+        return Ok(match op {
+            "*" => Operation::Multiply(n),
+            "+" => Operation::Add(n),
+            "-" => Operation::Subtract(n),
+            "/" => Operation::Divide(n),
+            _ => Operation::Remainder(n),
+        });
+    }
+
+    let word = one_argument(name, args, "an operation like `*2` or `square`")?;
+    match word.text {
+        "square" => Ok(Operation::Square),
+        "abs" => Ok(Operation::Abs),
+        "neg" => Ok(Operation::Negate),
+        other => error(word.position, format!("unknown operation `{}`", other)),
+    }
+}
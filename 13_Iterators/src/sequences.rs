@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::iter::FusedIterator;
+
+//Implementing Iterator only takes one method: next() returns Some(value) or None once there's
+//nothing left. Everything else (take, filter, sum, zip, ...) comes for free with the trait.
+//
+//These sequences go on "forever", which in a u64 means until the next value wouldn't fit.
+//Instead of panicking (or wrapping around to nonsense in release builds) the checked_* methods
+//give None on overflow, and that None simply ends the sequence.
+
+//0, 1, 1, 2, 3, 5, 8, ... each number is the sum of the two before it
+pub struct Fibonacci {
+    current: Option<u64>,
+    next: Option<u64>,
+}
+
+impl Fibonacci {
+    pub fn new() -> Fibonacci {
+        Fibonacci {
+            current: Some(0),
+            next: Some(1),
+        }
+    }
+}
+
+impl Default for Fibonacci {
+    fn default() -> Self {
+        Fibonacci::new()
+    }
+}
+
+impl Iterator for Fibonacci {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let current = self.current?;
+        //the one after `next` might not fit, but `next` itself still gets returned next time
+        let after = self.next.and_then(|next| current.checked_add(next));
+        self.current = self.next;
+        self.next = after;
+        Some(current)
+    }
+}
+
+impl FusedIterator for Fibonacci {}
+
+//2, 3, 5, 7, 11, ... with an incremental sieve of Eratosthenes. The usual sieve crosses out
+//multiples in a fixed-size array, which needs to know the limit up front. Here every prime found
+//so far waits in a map under its next multiple. When the candidate reaches that key it's not a
+//prime, and its primes move on to their next multiple. Any candidate nobody is waiting for is prime.
+pub struct Primes {
+    candidate: Option<u64>,
+    composites: HashMap<u64, Vec<u64>>, //next multiple -> the primes that divide it
+}
+
+impl Primes {
+    pub fn new() -> Primes {
+        Primes {
+            candidate: Some(2),
+            composites: HashMap::new(),
+        }
+    }
+}
+
+impl Default for Primes {
+    fn default() -> Self {
+        Primes::new()
+    }
+}
+
+impl Iterator for Primes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            let n = self.candidate?;
+            self.candidate = n.checked_add(1);
+            match self.composites.remove(&n) {
+                Some(primes) => {
+                    for p in primes {
+                        //a multiple past u64::MAX is never reached anyway, so it can be dropped
+                        if let Some(multiple) = n.checked_add(p) {
+                            self.composites.entry(multiple).or_default().push(p);
+                        }
+                    }
+                }
+                None => {
+                    //smaller multiples of n are already crossed out by smaller primes, start at n*n
+                    if let Some(square) = n.checked_mul(n) {
+                        self.composites.insert(square, vec![n]);
+                    }
+                    return Some(n);
+                }
+            }
+        }
+    }
+}
+
+impl FusedIterator for Primes {}
+
+//The Collatz sequence from `start`: halve even numbers, turn odd n into 3n + 1, stop at 1.
+//Nobody has proven that every start reaches 1, but every one anybody has tried does.
+//Starting at 0 gives nothing, 0 would just halve to itself forever.
+pub struct Collatz {
+    next: Option<u64>,
+}
+
+impl Collatz {
+    pub fn new(start: u64) -> Collatz {
+        Collatz {
+            next: Some(start).filter(|&n| n > 0),
+        }
+    }
+}
+
+impl Iterator for Collatz {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let n = self.next?;
+        self.next = match n {
+            1 => None,
+            n if n % 2 == 0 => Some(n / 2),
+            n => n.checked_mul(3).and_then(|n| n.checked_add(1)),
+        };
+        Some(n)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(_) => (1, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl FusedIterator for Collatz {}
+
+//1, 3, 6, 10, ... the n-th one is 1 + 2 + ... + n, the number of dots in a triangle with n rows
+pub struct Triangular {
+    row: u64,
+    total: Option<u64>,
+}
+
+impl Triangular {
+    pub fn new() -> Triangular {
+        Triangular {
+            row: 0,
+            total: Some(0),
+        }
+    }
+}
+
+impl Default for Triangular {
+    fn default() -> Self {
+        Triangular::new()
+    }
+}
+
+impl Iterator for Triangular {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let total = self.total?;
+        self.row += 1;
+        self.total = total.checked_add(self.row);
+        self.total
+    }
+
+    //The default nth calls next() n times. With the formula row * (row + 1) / 2 we can jump straight there.
+    fn nth(&mut self, n: usize) -> Option<u64> {
+        self.total?;
+        let row = (n as u64).checked_add(self.row).and_then(|r| r.checked_add(1));
+        self.row = row.unwrap_or(u64::MAX);
+        self.total = row.and_then(|row| (row as u128 * (row as u128 + 1) / 2).try_into().ok());
+        self.total
+    }
+}
+
+impl FusedIterator for Triangular {}
+
+//Any sequence where the next value comes from some state: `f` gets the state to update and
+//returns the next value, or None to end. Powers of two until they overflow:
+//    unfold(1u64, |n| { let current = *n; *n = n.checked_mul(2)?; Some(current) })
+//std has iter::successors and iter::from_fn for similar jobs, this one keeps the state out in the open.
+pub fn unfold<S, T, F>(initial: S, f: F) -> Unfold<S, F>
+where
+    F: FnMut(&mut S) -> Option<T>,
+{
+    Unfold {
+        state: initial,
+        f,
+        done: false,
+    }
+}
+
+pub struct Unfold<S, F> {
+    state: S,
+    f: F,
+    done: bool,
+}
+
+impl<S, F> Unfold<S, F> {
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S, T, F> Iterator for Unfold<S, F>
+where
+    F: FnMut(&mut S) -> Option<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.done {
+            return None;
+        }
+        let item = (self.f)(&mut self.state);
+        //once `f` said None, don't ask again, it might not expect that
+        self.done = item.is_none();
+        item
+    }
+}
+
+impl<S, T, F: FnMut(&mut S) -> Option<T>> FusedIterator for Unfold<S, F> {}
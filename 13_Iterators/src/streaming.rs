@@ -0,0 +1,248 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::str::FromStr;
+
+//`std::fs::read_to_string` loads the whole file before we see the first line, which doesn't work
+//for a log that's bigger than the memory we have. Reading through an iterator only ever holds the
+//current line: lines_from -> grep / parse_records -> Stats, each step pulling one line at a time.
+//
+//Every line is an io::Result, the disk can fail halfway through a file, and the error travels down
+//the chain like any other item until someone decides what to do with it (usually `?`).
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "I/O error: {}", e),
+            StreamError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+//Like BufRead::lines, but stops after the first error instead of possibly returning the same
+//error forever, and keeps count of where it is
+pub struct Lines<R> {
+    reader: R,
+    line_number: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Lines<R> {
+    pub fn new(reader: R) -> Lines<R> {
+        Lines {
+            reader,
+            line_number: 0,
+            done: false,
+        }
+    }
+
+    //The number of the line returned last, counting from 1
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+}
+
+pub fn lines_from(path: impl AsRef<Path>) -> io::Result<Lines<BufReader<File>>> {
+    Ok(Lines::new(BufReader::new(File::open(path)?)))
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<io::Result<String>> {
+        if self.done {
+            return None;
+        }
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => {
+                self.done = true;
+                None
+            }
+            Ok(_) => {
+                self.line_number += 1;
+                if line.ends_with('\n') {
+                    line.pop();
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                }
+                Some(Ok(line))
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+//The lines containing `pattern`, with their line numbers (from 1). Errors are passed on, not dropped.
+pub fn grep<I>(lines: I, pattern: &str) -> impl Iterator<Item = io::Result<(usize, String)>>
+where
+    I: IntoIterator<Item = io::Result<String>>,
+{
+    let pattern = pattern.to_string();
+    lines
+        .into_iter()
+        .enumerate()
+        .filter_map(move |(index, line)| match line {
+            Ok(line) if line.contains(&pattern) => Some(Ok((index + 1, line))),
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        })
+}
+
+//Parses every line with FromStr. Blank lines and lines starting with # are skipped,
+//a line that doesn't parse becomes an error that says which line it was.
+pub fn parse_records<T, I>(lines: I) -> impl Iterator<Item = Result<T, StreamError>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    I: IntoIterator<Item = io::Result<String>>,
+{
+    lines.into_iter().enumerate().filter_map(|(index, line)| {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => return Some(Err(StreamError::Io(e))),
+        };
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            return None;
+        }
+        Some(trimmed.parse().map_err(|e: T::Err| StreamError::Parse {
+            line: index + 1,
+            message: e.to_string(),
+        }))
+    })
+}
+
+//One line of a sensor log: `<sensor> <value>`, e.g. `kitchen 21.5`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reading {
+    pub sensor: String,
+    pub value: f64,
+}
+
+impl FromStr for Reading {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Reading, String> {
+        let mut parts = s.split_whitespace();
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(sensor), Some(value), None) => {
+                let value = value.parse().map_err(|e| format!("invalid value {:?}: {}", value, e))?;
+                Ok(Reading {
+                    sensor: sensor.to_string(),
+                    value,
+                })
+            }
+            _ => Err(format!("expected `sensor value`, got {:?}", s)),
+        }
+    }
+}
+
+//Count, sum, min and max, updated one value at a time so the values themselves never need to be
+//kept around. Collects straight from an iterator: `let stats: Stats = values.collect();`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub count: u64,
+    pub sum: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Stats {
+    pub fn new() -> Stats {
+        Stats {
+            count: 0,
+            sum: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn add(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    //None when nothing was added, there's no average of zero values
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum / self.count as f64)
+    }
+
+    pub fn merge(&mut self, other: &Stats) {
+        self.count += other.count;
+        self.sum += other.sum;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Stats::new()
+    }
+}
+
+impl Extend<f64> for Stats {
+    fn extend<T: IntoIterator<Item = f64>>(&mut self, values: T) {
+        for value in values {
+            self.add(value);
+        }
+    }
+}
+
+impl FromIterator<f64> for Stats {
+    fn from_iter<T: IntoIterator<Item = f64>>(values: T) -> Stats {
+        let mut stats = Stats::new();
+        stats.extend(values);
+        stats
+    }
+}
+
+//Stats per sensor. Memory grows with the number of different sensors, not with the number of lines.
+//Stops at the first error.
+pub fn stats_by_sensor<I>(readings: I) -> Result<BTreeMap<String, Stats>, StreamError>
+where
+    I: IntoIterator<Item = Result<Reading, StreamError>>,
+{
+    let mut by_sensor: BTreeMap<String, Stats> = BTreeMap::new();
+    for reading in readings {
+        let reading = reading?;
+        //get_mut first, so the sensor name is only copied the first time we see it
+        match by_sensor.get_mut(&reading.sensor) {
+            Some(stats) => stats.add(reading.value),
+            None => {
+                let mut stats = Stats::new();
+                stats.add(reading.value);
+                by_sensor.insert(reading.sensor, stats);
+            }
+        }
+    }
+    Ok(by_sensor)
+}
+
+//The whole chain for a sensor log file
+pub fn summarize(path: impl AsRef<Path>) -> Result<BTreeMap<String, Stats>, StreamError> {
+    stats_by_sensor(parse_records(lines_from(path)?))
+}
@@ -0,0 +1,108 @@
+use iterators::adapters::IterExt;
+
+//Runs an iterator to the end, checking at every step that size_hint brackets what's really left
+fn collect_checked<I: Iterator>(mut iter: I) -> Vec<I::Item> {
+    let mut items = Vec::new();
+    let mut hints = Vec::new();
+    loop {
+        hints.push(iter.size_hint());
+        match iter.next() {
+            Some(item) => items.push(item),
+            None => break,
+        }
+    }
+    let total = items.len();
+    for (taken, (lower, upper)) in hints.into_iter().enumerate() {
+        let left = total - taken;
+        assert!(lower <= left, "lower bound {} but only {} left", lower, left);
+        assert!(upper.is_none_or(|u| u >= left), "upper bound {:?} but {} left", upper, left);
+    }
+    items
+}
+
+#[test]
+fn chunks_exact_by() {
+    let mut chunks = (1..=7).chunks_exact_by(3);
+    assert_eq!(chunks.size_hint(), (2, Some(2)));
+    assert_eq!(chunks.next(), Some(vec![1, 2, 3]));
+    assert_eq!(chunks.next(), Some(vec![4, 5, 6]));
+    assert_eq!(chunks.next(), None);
+    assert_eq!(chunks.remainder(), &[7]);
+
+    assert_eq!(collect_checked((0..9).filter(|x| x % 2 == 0).chunks_exact_by(2)), [vec![0, 2], vec![4, 6]]);
+    assert!(collect_checked(std::iter::empty::<u8>().chunks_exact_by(1)).is_empty());
+}
+
+#[test]
+fn windows() {
+    assert_eq!(collect_checked([1, 2, 3, 4].into_iter().windows(2)), [vec![1, 2], vec![2, 3], vec![3, 4]]);
+    assert_eq!(collect_checked((1..=3).windows(3)), [vec![1, 2, 3]]);
+    assert!(collect_checked((1..=2).windows(3)).is_empty());
+    let strings = collect_checked(["a", "b", "c"].iter().map(|s| s.to_string()).windows(1));
+    assert_eq!(strings, [vec!["a"], vec!["b"], vec!["c"]]);
+}
+
+#[test]
+fn interleave() {
+    assert_eq!(collect_checked([1, 3, 5].into_iter().interleave([2, 4])), [1, 2, 3, 4, 5]);
+    assert_eq!(collect_checked([1].into_iter().interleave([2, 4, 6])), [1, 2, 4, 6]);
+    assert_eq!(collect_checked((0..0).interleave(0..0)), Vec::<i32>::new());
+}
+
+#[test]
+fn dedup_by_key() {
+    let words = ["apple", "avocado", "banana", "blueberry", "apricot", "cherry"];
+    let firsts = collect_checked(words.into_iter().dedup_by_key(|w| w.chars().next()));
+    assert_eq!(firsts, ["apple", "banana", "apricot", "cherry"]); //only neighbours count as duplicates
+    assert_eq!(collect_checked([1, 1, 1].into_iter().dedup_by_key(|&x| x)), [1]);
+}
+
+#[test]
+fn intersperse() {
+    //called through the trait, see the comment on IterExt::intersperse
+    assert_eq!(collect_checked(IterExt::intersperse(["a", "b", "c"].into_iter(), ",")).concat(), "a,b,c");
+    assert_eq!(collect_checked(IterExt::intersperse([1].into_iter(), 0)), [1]);
+    assert_eq!(collect_checked(IterExt::intersperse(std::iter::empty(), 0)), Vec::<i32>::new());
+    assert_eq!(collect_checked(IterExt::intersperse((1..=6).filter(|x| x % 2 == 1), 0)), [1, 0, 3, 0, 5]);
+}
+
+#[test]
+fn batching() {
+    //length-prefixed records: 2 items, then 3 items, then 1 item
+    let data = [2, 10, 11, 3, 20, 21, 22, 1, 30];
+    let records = collect_checked(data.into_iter().batching(|it| {
+        let len = it.next()?;
+        Some(it.take(len as usize).collect::<Vec<_>>())
+    }));
+    assert_eq!(records, [vec![10, 11], vec![20, 21, 22], vec![30]]);
+
+    let pairs = collect_checked((1..=5).batching(|it| Some((it.next()?, it.next()))));
+    assert_eq!(pairs, [(1, Some(2)), (3, Some(4)), (5, None)]);
+}
+
+#[test]
+fn scan_while() {
+    let totals = collect_checked([1, 2, 3, 4, 5].into_iter().scan_while(0, |sum, x| sum + x, |&sum| sum < 10));
+    assert_eq!(totals, [1, 3, 6]);
+
+    let sentence = collect_checked("the quick brown fox".split(' ').scan_while(String::new(), |line, word| {
+        if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) }
+    }, |line| line.len() <= 10));
+    assert_eq!(sentence, ["the", "the quick"]);
+}
+
+#[test]
+fn adapters_are_lazy() {
+    //only pulls as much from the (infinite) source as the consumer asks for
+    let mut pulled = 0;
+    let first: Vec<Vec<u32>> = (0..)
+        .inspect(|_| pulled += 1)
+        .windows(3)
+        .take(2)
+        .collect();
+    assert_eq!(first, [vec![0, 1, 2], vec![1, 2, 3]]);
+    assert_eq!(pulled, 4);
+
+    let mixed: Vec<u32> = (0..).step_by(2).interleave((1..).step_by(2)).take(6).collect();
+    assert_eq!(mixed, [0, 1, 2, 3, 4, 5]);
+}
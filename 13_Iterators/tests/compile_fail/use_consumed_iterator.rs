@@ -0,0 +1,10 @@
+pub fn use_consumed_iterator() {
+    let v1 = vec![1, 2, 3, 4, 5];
+    let v1_iter = v1.iter();
+    let sum: i32 = v1_iter.sum(); //sum takes the iterator by value and consumes it
+    println!("Sum: {}", sum);
+
+    for i in v1_iter { //ERR: E0382 Use of moved value: `v1_iter`
+        println!("{}", i);
+    }
+}
@@ -0,0 +1,89 @@
+use iterators::parallel::{ParIter, ParSlice, Splitting};
+use proptest::prelude::*;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::thread;
+
+#[test]
+fn same_answers_as_the_lesson() {
+    let v1 = vec![1, 2, 3, 4, 5];
+    assert_eq!(v1.par_iter().sum::<i32>(), 15);
+    let even: Vec<&i32> = v1.par_iter().filter(|x| *x % 2 == 0).collect();
+    assert_eq!(even, [&2, &4]);
+
+    //the assignment: odd values doubled
+    let doubled: Vec<i32> = v1.par_iter().filter(|x| *x % 2 != 0).map(|x| x * 2).collect();
+    assert_eq!(doubled, [2, 6, 10]);
+}
+
+#[test]
+fn empty_input() {
+    let empty: Vec<u64> = Vec::new();
+    assert_eq!(empty.par_iter().sum::<u64>(), 0);
+    assert_eq!(empty.par_iter().count(), 0);
+    assert_eq!(empty.par_iter().with_threads(4).with_min_chunk(1).collect::<Vec<_>>(), Vec::<&u64>::new());
+}
+
+#[test]
+fn work_is_spread_over_threads() {
+    let numbers: Vec<u32> = (0..1000).collect();
+    let seen = Mutex::new(HashSet::new());
+    let count = numbers
+        .par_iter()
+        .with_threads(4)
+        .with_min_chunk(1)
+        .map(|x| {
+            seen.lock().unwrap().insert(thread::current().id());
+            x
+        })
+        .count();
+    assert_eq!(count, 1000);
+    assert_eq!(seen.lock().unwrap().len(), 4);
+
+    //small inputs stay on the calling thread
+    let seen = Mutex::new(HashSet::new());
+    numbers.par_iter().with_threads(4).map(|_| seen.lock().unwrap().insert(thread::current().id())).count();
+    assert_eq!(*seen.lock().unwrap(), HashSet::from([thread::current().id()]));
+}
+
+#[test]
+fn splitting_defaults() {
+    let splitting = Splitting::default();
+    assert!(splitting.threads >= 1);
+    assert_eq!([1].par_iter().splitting(), splitting);
+}
+
+#[test]
+#[should_panic(expected = "found the bad number")]
+fn panics_reach_the_caller() {
+    let numbers: Vec<u32> = (0..100).collect();
+    numbers
+        .par_iter()
+        .with_threads(4)
+        .with_min_chunk(1)
+        .map(|&x| if x == 77 { panic!("found the bad number") } else { x })
+        .sum::<u32>();
+}
+
+proptest! {
+    //However the input is cut up, the results match the sequential chain, in the same order
+    #[test]
+    fn matches_sequential(
+        numbers in proptest::collection::vec(-10_000i64..10_000, 0..300),
+        threads in 1usize..9,
+        min_chunk in 0usize..50,
+        divisor in 1i64..5,
+    ) {
+        let par = || numbers.par_iter().with_threads(threads).with_min_chunk(min_chunk);
+
+        let collected: Vec<i64> = par().filter(|x| *x % divisor == 0).map(|x| x * 3).collect();
+        let expected: Vec<i64> = numbers.iter().filter(|x| *x % divisor == 0).map(|x| x * 3).collect();
+        prop_assert_eq!(&collected, &expected);
+
+        prop_assert_eq!(par().filter(|x| *x % divisor == 0).map(|x| x * 3).sum::<i64>(), expected.iter().sum::<i64>());
+        prop_assert_eq!(par().filter(|x| *x % divisor == 0).count(), expected.len());
+
+        let as_strings: String = par().map(|x| x.to_string()).collect();
+        prop_assert_eq!(as_strings, numbers.iter().map(|x| x.to_string()).collect::<String>());
+    }
+}
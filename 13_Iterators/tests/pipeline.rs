@@ -0,0 +1,98 @@
+use iterators::adapters::IterExt;
+use iterators::pipeline::{Output, ParseError, Pipeline};
+use proptest::prelude::*;
+
+fn run(source: &str, input: &[i64]) -> Output {
+    Pipeline::parse(source).unwrap().run(input.to_vec())
+}
+
+fn parse_error(source: &str) -> ParseError {
+    Pipeline::parse(source).unwrap_err()
+}
+
+#[test]
+fn the_assignment() {
+    let input = [1, 2, 3, 4, 5];
+    let by_hand: Vec<i64> = input.iter().filter(|x| *x % 2 != 0).map(|x| x * 2).collect();
+    assert_eq!(run("filter odd | map *2", &input), Output::Values(by_hand));
+}
+
+#[test]
+fn terminals() {
+    let input = [3, 1, 4, 1, 5, 9, 2, 6];
+    assert_eq!(run("sum", &input), Output::Number(31));
+    assert_eq!(run("filter even | product", &input), Output::Number(48));
+    assert_eq!(run("filter > 3 | count", &input), Output::Number(4));
+    assert_eq!(run("min", &input), Output::Number(1));
+    assert_eq!(run("map neg | max", &input), Output::Number(-1));
+    assert_eq!(run("filter > 100 | max", &input), Output::Empty);
+    assert_eq!(run("filter >100 | sum", &input), Output::Number(0));
+}
+
+#[test]
+fn every_stage_kind() {
+    let input = [-3, -3, 0, 4, 4, 4, 7, 10];
+    assert_eq!(run("dedup", &input), Output::Values(vec![-3, 0, 4, 7, 10]));
+    assert_eq!(run("skip 2 | take 3", &input), Output::Values(vec![0, 4, 4]));
+    assert_eq!(run("filter negative | map abs", &input), Output::Values(vec![3, 3]));
+    assert_eq!(run("filter divisible 5 | map square", &input), Output::Values(vec![0, 100]));
+    assert_eq!(run("filter != 4 | map + 1 | map %3", &input), Output::Values(vec![-2, -2, 1, 2, 2]));
+    assert_eq!(run("map -1 | map / 2 | filter <= 1", &input), Output::Values(vec![-2, -2, 0, 1, 1, 1]));
+    assert_eq!(run("filter == 4 | count", &input), Output::Number(3));
+}
+
+#[test]
+fn arithmetic_wraps_instead_of_panicking() {
+    //i64::MIN / -1 doesn't fit in an i64, so plain `%` and `/` panic on it
+    let input = [i64::MIN, 3];
+    assert_eq!(run("filter divisible -1", &input), Output::Values(vec![i64::MIN, 3]));
+    assert_eq!(run("map %-1", &input), Output::Values(vec![0, 0]));
+    assert_eq!(run("map /-1", &input), Output::Values(vec![i64::MIN, -3]));
+}
+
+#[test]
+fn stages_are_lazy() {
+    //take stops pulling from an infinite input after the third match
+    let pipeline = Pipeline::parse("filter divisible 7 | take 3 | sum").unwrap();
+    assert_eq!(pipeline.run(1..), Output::Number(7 + 14 + 21));
+}
+
+#[test]
+fn parse_errors_point_at_the_problem() {
+    let err = parse_error("filter odd | mapp *2");
+    assert_eq!(err.position, 13);
+    assert_eq!(err.to_string(), "column 14: unknown stage `mapp`");
+    assert_eq!(err.show("filter odd | mapp *2"), "filter odd | mapp *2\n             ^ unknown stage `mapp`");
+
+    assert_eq!(parse_error("map *x").position, 5);
+    assert_eq!(parse_error("map / 0").message, "can't divide by zero");
+    assert_eq!(parse_error("filter odd | | sum").position, 13);
+    assert_eq!(parse_error("take -1").message, "expected a count, found `-1`");
+    assert_eq!(parse_error("take 1 2").position, 7);
+    assert_eq!(parse_error("sum 3").message, "`sum` takes no arguments");
+    assert_eq!(parse_error("count | map *2").message, "`count` has to be the last stage");
+    assert_eq!(parse_error("filter").position, 6);
+    assert_eq!(parse_error("filter prime").message, "unknown condition `prime`");
+    assert_eq!(parse_error("filter odd even").position, 11);
+}
+
+proptest! {
+    //The text pipeline and the same chain written by hand always agree
+    #[test]
+    fn matches_hand_written_chains(
+        input in proptest::collection::vec(-1000i64..1000, 0..50),
+        factor in -10i64..10,
+        limit in -500i64..500,
+        take in 0usize..60,
+    ) {
+        let source = format!("filter odd | map *{} | filter > {} | take {}", factor, limit, take);
+        let by_hand: Vec<i64> = input.iter().copied().filter(|x| x % 2 != 0).map(|x| x * factor).filter(|&x| x > limit).take(take).collect();
+        prop_assert_eq!(run(&source, &input), Output::Values(by_hand.clone()));
+
+        let sum = format!("{} | sum", source);
+        prop_assert_eq!(run(&sum, &input), Output::Number(by_hand.iter().sum()));
+
+        let deduped: Vec<i64> = input.iter().copied().map(|x| x / 100).dedup_by_key(|&x| x).collect();
+        prop_assert_eq!(run("map /100 | dedup", &input), Output::Values(deduped));
+    }
+}
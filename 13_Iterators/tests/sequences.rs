@@ -0,0 +1,102 @@
+use iterators::sequences::{unfold, Collatz, Fibonacci, Primes, Triangular};
+
+#[test]
+fn fibonacci_starts_right_and_stops_before_overflowing() {
+    let first: Vec<u64> = Fibonacci::new().take(10).collect();
+    assert_eq!(first, [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+
+    //F(93) is the last one that fits in a u64
+    let all: Vec<u64> = Fibonacci::new().collect();
+    assert_eq!(all.len(), 94);
+    assert_eq!(*all.last().unwrap(), 12_200_160_415_121_876_738);
+    assert!(all.windows(3).all(|w| w[0] + w[1] == w[2]));
+
+    let mut fib = Fibonacci::new().skip(94);
+    assert_eq!(fib.next(), None);
+    assert_eq!(fib.next(), None);
+}
+
+fn is_prime(n: u64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
+
+#[test]
+fn primes_match_trial_division() {
+    let first: Vec<u64> = Primes::new().take(10).collect();
+    assert_eq!(first, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+
+    let sieved: Vec<u64> = Primes::new().take_while(|&p| p < 10_000).collect();
+    let by_hand: Vec<u64> = (0..10_000).filter(|&n| is_prime(n)).collect();
+    assert_eq!(sieved, by_hand);
+    assert_eq!(Primes::new().nth(999), Some(7919));
+}
+
+#[test]
+fn collatz() {
+    let from_6: Vec<u64> = Collatz::new(6).collect();
+    assert_eq!(from_6, [6, 3, 10, 5, 16, 8, 4, 2, 1]);
+    assert_eq!(Collatz::new(27).count(), 112);
+    assert_eq!(Collatz::new(27).max(), Some(9232));
+    assert_eq!(Collatz::new(1).collect::<Vec<_>>(), [1]);
+    assert_eq!(Collatz::new(0).next(), None);
+
+    //3n + 1 doesn't fit, the sequence ends instead of panicking
+    let huge: Vec<u64> = Collatz::new(u64::MAX).collect();
+    assert_eq!(huge, [u64::MAX]);
+}
+
+#[test]
+fn triangular_numbers() {
+    let first: Vec<u64> = Triangular::new().take(5).collect();
+    assert_eq!(first, [1, 3, 6, 10, 15]);
+    assert!(Triangular::new().zip(1u64..).take(1000).all(|(t, n)| t == n * (n + 1) / 2));
+
+    let mut skipping = Triangular::new();
+    assert_eq!(skipping.nth(3), Some(10));
+    assert_eq!(skipping.next(), Some(15));
+
+    //row 6_074_000_999 is the last one that fits in a u64
+    let mut last = Triangular::new();
+    assert_eq!(last.nth(6_074_000_998), Some(18_446_744_070_963_499_500));
+    assert_eq!(last.next(), None);
+    assert_eq!(last.next(), None);
+}
+
+#[test]
+fn unfold_keeps_state_between_calls() {
+    let powers: Vec<u64> = unfold(1u64, |n| {
+        let current = *n;
+        *n = n.checked_mul(2)?;
+        Some(current)
+    })
+    .collect();
+    //2^63 gets lost: computing the one after it fails first. That's how this closure is written.
+    assert_eq!(powers.len(), 63);
+    assert_eq!(powers[10], 1024);
+
+    let mut countdown = unfold(3, |n: &mut i32| {
+        *n -= 1;
+        (*n >= 0).then_some(*n)
+    });
+    assert_eq!(countdown.by_ref().collect::<Vec<_>>(), [2, 1, 0]);
+    assert_eq!(countdown.next(), None);
+    assert_eq!(*countdown.state(), -1);
+}
+
+#[test]
+fn works_with_std_adapters() {
+    //even Fibonacci numbers below four million (Project Euler #2)
+    let sum: u64 = Fibonacci::new().take_while(|&f| f < 4_000_000).filter(|f| f % 2 == 0).sum();
+    assert_eq!(sum, 4_613_732);
+
+    //triangular numbers that are also prime: only 3
+    let primes: Vec<u64> = Primes::new().take_while(|&p| p < 1000).collect();
+    let both: Vec<u64> = Triangular::new()
+        .take_while(|&t| t < 1000)
+        .filter(|t| primes.binary_search(t).is_ok())
+        .collect();
+    assert_eq!(both, [3]);
+
+    let longest = (1..1000u64).max_by_key(|&n| Collatz::new(n).count());
+    assert_eq!(longest, Some(871));
+}
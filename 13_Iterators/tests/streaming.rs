@@ -0,0 +1,112 @@
+use iterators::streaming::{grep, parse_records, stats_by_sensor, summarize, Lines, Reading, Stats, StreamError};
+use std::io::{self, BufReader, Cursor, Read};
+
+const LOG: &str = "# sensor log\nkitchen 21.5\r\ngarage 4\n\nkitchen 22.5\ncellar 12\ngarage -2\n";
+
+fn lines(text: &str) -> Lines<Cursor<Vec<u8>>> {
+    Lines::new(Cursor::new(text.as_bytes().to_vec()))
+}
+
+#[test]
+fn lines_strip_line_endings() {
+    let all: Vec<String> = lines("one\r\ntwo\n\nlast without newline").map(Result::unwrap).collect();
+    assert_eq!(all, ["one", "two", "", "last without newline"]);
+
+    let mut counted = lines("a\nb\n");
+    counted.next();
+    assert_eq!(counted.line_number(), 1);
+    counted.next();
+    assert_eq!(counted.line_number(), 2);
+    assert!(counted.next().is_none());
+}
+
+#[test]
+fn grep_keeps_line_numbers() {
+    let hits: Vec<(usize, String)> = grep(lines(LOG), "garage").map(Result::unwrap).collect();
+    assert_eq!(hits, [(3, "garage 4".to_string()), (7, "garage -2".to_string())]);
+    assert_eq!(grep(lines(LOG), "attic").count(), 0);
+}
+
+#[test]
+fn parse_records_skips_comments_and_reports_bad_lines() {
+    let readings: Vec<Reading> = parse_records(lines(LOG)).map(Result::unwrap).collect();
+    assert_eq!(readings.len(), 5);
+    assert_eq!(
+        readings[0],
+        Reading {
+            sensor: "kitchen".to_string(),
+            value: 21.5
+        }
+    );
+
+    let results: Vec<Result<Reading, StreamError>> = parse_records(lines("kitchen 20\nkitchen warm\nkitchen\n")).collect();
+    assert!(results[0].is_ok());
+    assert_eq!(
+        results[1].as_ref().unwrap_err().to_string(),
+        "line 2: invalid value \"warm\": invalid float literal"
+    );
+    assert!(matches!(results[2], Err(StreamError::Parse { line: 3, .. })));
+
+    //any FromStr type works, not just Reading
+    let numbers: Result<Vec<u32>, StreamError> = parse_records(lines("1\n2\n# three\n4\n")).collect();
+    assert_eq!(numbers.unwrap(), [1, 2, 4]);
+}
+
+#[test]
+fn stats() {
+    let stats: Stats = [3.0, 1.0, 2.0].into_iter().collect();
+    assert_eq!(stats.count, 3);
+    assert_eq!(stats.mean(), Some(2.0));
+    assert_eq!((stats.min, stats.max), (1.0, 3.0));
+    assert_eq!(Stats::new().mean(), None);
+
+    let mut merged: Stats = [10.0].into_iter().collect();
+    merged.merge(&stats);
+    assert_eq!(merged, [3.0, 1.0, 2.0, 10.0].into_iter().collect());
+
+    let by_sensor = stats_by_sensor(parse_records(lines(LOG))).unwrap();
+    let sensors: Vec<&String> = by_sensor.keys().collect();
+    assert_eq!(sensors, ["cellar", "garage", "kitchen"]);
+    assert_eq!(by_sensor["kitchen"].mean(), Some(22.0));
+    assert_eq!(by_sensor["garage"].min, -2.0);
+}
+
+#[test]
+fn summarize_a_file() {
+    let path = std::env::temp_dir().join(format!("iterators_streaming_{}.log", std::process::id()));
+    std::fs::write(&path, LOG).unwrap();
+    let summary = summarize(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(summary.unwrap()["cellar"].count, 1);
+
+    assert!(matches!(summarize("no/such/file.log"), Err(StreamError::Io(_))));
+}
+
+//Fails every read, chained after some good data it's a disk dying halfway through a file
+struct Broken;
+
+impl Read for Broken {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk on fire"))
+    }
+}
+
+fn failing_after(text: &'static str) -> Lines<BufReader<impl Read>> {
+    Lines::new(BufReader::new(text.as_bytes().chain(Broken)))
+}
+
+#[test]
+fn errors_come_through_once() {
+    let mut failing = failing_after("kitchen 1\n");
+    assert_eq!(failing.next().unwrap().unwrap(), "kitchen 1");
+    assert_eq!(failing.next().unwrap().unwrap_err().to_string(), "disk on fire");
+    assert!(failing.next().is_none());
+
+    let hits: Vec<io::Result<(usize, String)>> = grep(failing_after("kitchen 1\ngarage 2\n"), "garage").collect();
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].as_ref().unwrap(), &(2, "garage 2".to_string()));
+    assert!(hits[1].is_err());
+
+    let summary = stats_by_sensor(parse_records(failing_after("kitchen 1\n")));
+    assert!(matches!(summary, Err(StreamError::Io(_))));
+}
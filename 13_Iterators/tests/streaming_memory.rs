@@ -0,0 +1,92 @@
+//Its own test binary, because it swaps in an allocator that counts every byte the process has
+//allocated, and other tests running at the same time would mess up the count.
+//Writes and reads ~300 MB, so it only runs when asked for:
+//    cargo test -p iterators --release --test streaming_memory -- --ignored
+use iterators::streaming::{lines_from, summarize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct Counting;
+
+static IN_USE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            let now = IN_USE.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            PEAK.fetch_max(now, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        IN_USE.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+const MB: usize = 1024 * 1024;
+const SENSORS: usize = 16;
+
+//Deletes the file even when an assert fails halfway, it's a few hundred MB
+struct TempFile(PathBuf);
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+//How much more than before `f` was in use at the worst moment while it ran
+fn peak_while<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = IN_USE.load(Ordering::SeqCst);
+    PEAK.store(before, Ordering::SeqCst);
+    let result = f();
+    (result, PEAK.load(Ordering::SeqCst) - before)
+}
+
+#[test]
+#[ignore = "writes a 300 MB file, run it with --release --ignored"]
+fn a_file_much_bigger_than_the_memory_used() {
+    //one block of lines written over and over, about 300 MB in total
+    let mut block = String::new();
+    for i in 0..4000 {
+        block.push_str(&format!("greenhouse-thermometer-{:02} {}.5\n", i % SENSORS, i % 40));
+    }
+    let repeats = 300 * MB / block.len();
+
+    let file = TempFile(std::env::temp_dir().join(format!("iterators_streaming_big_{}.log", std::process::id())));
+    let path = &file.0;
+    {
+        let mut file = BufWriter::new(File::create(path).unwrap());
+        for _ in 0..repeats {
+            file.write_all(block.as_bytes()).unwrap();
+        }
+        file.flush().unwrap();
+    }
+    let size = std::fs::metadata(path).unwrap().len() as usize;
+
+    let (line_count, counting_peak) = peak_while(|| lines_from(path).unwrap().count());
+    let (summary, summarize_peak) = peak_while(|| summarize(path));
+    drop(file);
+    let summary = summary.unwrap();
+
+    assert!(size > 250 * MB, "the file should be a few hundred MB, it's {} bytes", size);
+    assert_eq!(line_count, 4000 * repeats);
+    assert_eq!(summary.len(), SENSORS);
+    assert_eq!(summary.values().map(|s| s.count).sum::<u64>(), line_count as u64);
+    assert_eq!(summary["greenhouse-thermometer-00"].min, 0.5);
+    assert_eq!(summary["greenhouse-thermometer-15"].max, 39.5);
+
+    //the reader's buffer, one line and one entry per sensor, nothing like the size of the file
+    assert!(counting_peak < MB / 4, "counting lines needed {} bytes at once", counting_peak);
+    assert!(summarize_peak < MB / 4, "summarizing needed {} bytes at once", summarize_peak);
+}
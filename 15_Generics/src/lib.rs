@@ -0,0 +1,29 @@
+use std::io::{self, Write};
+
+pub fn run(out: &mut impl Write) -> io::Result<()>{
+    let bigger = largest(1,2);
+    let bigger_char = largest('a', 'b');
+    writeln!(out, "Bigger i32: {}", bigger)?;
+    writeln!(out, "Bigger char: {}", bigger_char)?;
+    Ok(())
+}
+fn largest<T: std::cmp::PartialOrd>(a: T, b: T) -> T {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+pub struct Generics;
+
+impl bootcamp_core::Lesson for Generics {
+    fn dir(&self) -> &'static str {
+        "15_Generics"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
@@ -1,13 +1,3 @@
-fn main(){
-    let bigger = largest(1,2);
-    let bigger_char = largest('a', 'b');
-    println!("Bigger i32: {}", bigger);
-    println!("Bigger char: {}", bigger_char);
+fn main() {
+    generics::run(&mut std::io::stdout()).unwrap();
 }
-fn largest<T: std::cmp::PartialOrd>(a: T, b: T) -> T {
-    if a > b {
-        a
-    } else {
-        b
-    }
-}
\ No newline at end of file
@@ -0,0 +1,75 @@
+use std::io::{self, Write};
+
+pub trait Summary {
+    //similar to abstract class. a class whose function we are defining here
+    fn summarize(&self) -> String {
+        return String::from("No summary available");
+    }
+}
+//Trait is like a blueprint that other structs may follow.
+
+pub trait  Fix {
+    fn fix(&self) -> String {
+        return String::from("No fix available");
+    }
+}
+
+struct User {
+    name: String,
+    age: u32,
+}
+
+struct DefaultUser {}
+
+impl Summary for User {
+    //impl is used to implement the trait for a struct
+    fn summarize(&self) -> String {
+        format!("{} is {} years old", self.name, self.age)
+    }
+}
+impl Fix for User {
+    fn fix(&self) -> String {
+        format!("Fixing user: {}", self.name)
+    }
+}
+
+impl Summary for DefaultUser {}
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    let user = User {
+        name: String::from("Curator"),
+        age: 30,
+    };
+    let default_user = DefaultUser {};
+
+    writeln!(out, "{}", user.summarize())?; //using the summarize function defined in the trait
+    writeln!(out, "{}", default_user.summarize())?;
+    notify(&user, out)?;
+    notify_fix(user, out)?;
+    // notify(default_user);
+    Ok(())
+}
+
+fn notify(item: &impl Summary, out: &mut impl Write) -> io::Result<()> {//now we know that anything that uses this trait will have a summarize function
+    writeln!(out, "New notification: {}", item.summarize())?;
+    Ok(())
+}
+
+fn notify_fix<T: Summary + Fix>(item: T, out: &mut impl Write) -> io::Result<()> { //Trait bounds
+    writeln!(out, "New notification: {}", item.summarize())?;
+    writeln!(out, "Fix available: {}", item.fix())?;
+    Ok(())
+}
+
+pub struct Traits;
+
+impl bootcamp_core::Lesson for Traits {
+    fn dir(&self) -> &'static str {
+        "16_Traits"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
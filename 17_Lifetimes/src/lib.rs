@@ -0,0 +1,35 @@
+use std::io::{self, Write};
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    let ans;
+    let str1 = String::from("small");
+    {
+        let str2 = String::from("longer");
+        ans = longest(&str1, &str2);
+        writeln!(out, "The longest string is: {}", ans)?;
+    }
+    // println!("The longest string is: {}", ans); Error: `str2` does not live long enough
+    Ok(())
+}
+
+
+fn longest<'a>(str1: &'a str, str2: &'a str) -> &'a str { //ERROR: Missing Lifetime Specifier -this function's return type contains a borrowed value, but the signature does not say whether it is borrowed from `a` or `b`
+    if str1.len() > str2.len() {
+        str1
+    } else {
+        str2
+    }
+}
+
+pub struct Lifetimes;
+
+impl bootcamp_core::Lesson for Lifetimes {
+    fn dir(&self) -> &'static str {
+        "17_Lifetimes"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
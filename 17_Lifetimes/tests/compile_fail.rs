@@ -0,0 +1,7 @@
+use bootcamp_core::compile_fail::assert_compile_errors;
+
+//The examples in tests/compile_fail must keep failing with the errors the lesson talks about
+#[test]
+fn documented_errors_still_happen() {
+    assert_compile_errors(env!("CARGO_MANIFEST_DIR"));
+}
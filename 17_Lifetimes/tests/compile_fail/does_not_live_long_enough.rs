@@ -0,0 +1,13 @@
+fn longest<'a>(str1: &'a str, str2: &'a str) -> &'a str {
+    if str1.len() > str2.len() { str1 } else { str2 }
+}
+
+pub fn does_not_live_long_enough() {
+    let ans;
+    let str1 = String::from("small");
+    {
+        let str2 = String::from("longer");
+        ans = longest(&str1, &str2); //ERR: E0597 `str2` does not live long enough
+    }
+    println!("The longest string is: {}", ans);
+}
@@ -0,0 +1,4 @@
+//Without 'a the compiler can't tell whether the returned reference is borrowed from str1 or str2
+pub fn longest(str1: &str, str2: &str) -> &str { //ERR: E0106 Missing Lifetime Specifier
+    if str1.len() > str2.len() { str1 } else { str2 }
+}
@@ -0,0 +1,30 @@
+use std::io::{self, Write};
+
+// struct User {
+//     name: &str,
+//}//How do we know, how long is user valid for? Shouldn't it be tied to the lifetime of name? As if name goes out of scope then user should also go out of scope.
+
+struct User<'a> {
+    name: &'a str, // Here we are saying that the lifetime of name is tied to the lifetime of User
+}
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    let name = String::from("Alice");
+    let user = User { name: &name };
+
+    writeln!(out, "User name: {}", user.name)?;
+    Ok(())
+}
+
+pub struct StructsWithLifetimes;
+
+impl bootcamp_core::Lesson for StructsWithLifetimes {
+    fn dir(&self) -> &'static str {
+        "18_Structs_with_Lifetimes"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use std::io::{self, Write};
+use std::thread;
+// use std::time::Duration;
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    // let handle = thread::spawn(|| {
+    //     for i in 1..10 {
+    //         println!("Hi number {i} from the spawned thread");
+    //         thread::sleep(Duration::from_millis(1));
+    //     }
+    // });
+
+    
+    // for i in 1..5 {
+    //     println!("Hi number {i} from the main thread");
+    //     thread::sleep(Duration::from_millis(1));
+    // }
+    // handle.join().unwrap(); //Wait for the spawned thread to finish and only then proceed
+
+    let v = vec![1, 2, 3];
+    // thread::spawn(|| { //ERR: May outlive borrowed value `v` so we gotta use move keyword to force ownership of the variable v (u cant use it after this though)
+    //     println!("Here's a vector: {:?}", v);
+    // });
+
+    let handle = thread::spawn(move || { //Now v is owned by the spawned thread
+        format!("Here's a vector: {:?}", v) //`out` is borrowed by main, so the thread hands its text back instead of writing it
+    });
+    writeln!(out, "{}", handle.join().unwrap())?; //join so the output doesn't depend on whether the thread finished first
+    Ok(())
+}
+
+pub struct Multithreading;
+
+impl bootcamp_core::Lesson for Multithreading {
+    fn dir(&self) -> &'static str {
+        "19_Multithreading"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+use std::thread;
+
+pub fn closure_may_outlive() {
+    let v = vec![1, 2, 3];
+    thread::spawn(|| { //ERR: E0373 May outlive borrowed value `v`, needs the move keyword
+        println!("Here's a vector: {:?}", v);
+    });
+}
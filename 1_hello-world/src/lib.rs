@@ -0,0 +1,19 @@
+use std::io::{self, Write};
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "Hello, world!")?;
+    Ok(())
+}
+
+pub struct HelloWorld;
+
+impl bootcamp_core::Lesson for HelloWorld {
+    fn dir(&self) -> &'static str {
+        "1_hello-world"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
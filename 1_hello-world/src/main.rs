@@ -1,3 +1,3 @@
 fn main() {
-    println!("Hello, world!");
+    hello_world::run(&mut std::io::stdout()).unwrap();
 }
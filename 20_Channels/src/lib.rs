@@ -0,0 +1,74 @@
+use std::io::{self, Write};
+use std::{sync::mpsc, thread};
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+
+    // let (tx,rx) = mpsc::channel();
+
+
+    // thread::spawn(move || {
+    //     let val = String::from("hi");
+    //     println!("Sending: {}", val);
+    //     tx.send(val).unwrap();
+    // });
+
+    // let received = rx.recv();
+    // match received {
+    //     Ok(v) => println!("Received: {}", v),
+    //     Err(e) => println!("Error: {}", e),
+    // }
+    add_big(out)?;
+    Ok(())
+}
+
+// Assignment: Write a code that finds sum from 1 to 10^8. Use threads to make sure you use all cores on your machine. Remember its "multiple producers" and "single consumer" model.
+//Try it yourself in exercises/assignments/20_parallel_sum.rs before reading the answer, `cargo run -p exercises -- watch` checks it every time you save
+
+//Example ans:
+
+fn add_big(out: &mut impl Write) -> io::Result<()> {
+    let (tx, rx) = mpsc::channel();
+
+    for i in 0..8{
+        let producer = tx.clone(); // Clone the transmitter for each thread
+        
+        thread::spawn(move || {
+            let mut sum: u64 = 0;
+            for j in i*10000000..(i+1)*10000000-1{
+                sum = sum + j;
+            }
+            producer.send(sum).unwrap();
+        });
+    }
+
+    drop(tx); // Close the original transmitter to avoid hanging otherwise receiver will keep waiting thinking there's more to come
+
+    let mut final_sum: u64 = 0;
+    let mut received = Vec::new();
+
+    for val in rx {
+        final_sum = final_sum + val;
+        received.push(val);
+    }
+
+    received.sort(); //threads finish in any order, sorting keeps the printed output the same on every run
+    for val in received {
+        writeln!(out, "Received: {}", val)?;
+    }
+
+    writeln!(out, "Final Answer: {}", final_sum)?;
+    Ok(())
+}
+
+pub struct Channels;
+
+impl bootcamp_core::Lesson for Channels {
+    fn dir(&self) -> &'static str {
+        "20_Channels"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+pub mod rng;
+pub mod stats;
+
+use rng::{Pcg32, Rng, Xorshift64};
+use std::io::{self, Write};
+
+pub fn demo(seed: u64, out: &mut impl Write) -> io::Result<()> {
+    //Same seed -> same numbers, every time. Great for tests and reproducible simulations.
+    let mut rng = Pcg32::seed(seed);
+
+    writeln!(out, "Random number: {}", rng.next_u32())?;
+    writeln!(out, "Dice roll: {}", rng.gen_range(1..7))?;
+    writeln!(out, "Random float: {:.4}", rng.next_f64())?;
+
+    let mut cards = vec!["A", "K", "Q", "J", "10"];
+    rng.shuffle(&mut cards);
+    writeln!(out, "Shuffled: {:?}", cards)?;
+
+    let loot = ["common", "rare", "legendary"];
+    let picked = rng.choose_weighted(&loot, &[90.0, 9.0, 1.0]);
+    writeln!(out, "Loot drop: {:?}", picked)?; //Option, because the weights might be invalid
+
+    let height = rng.gaussian(170.0, 10.0);
+    writeln!(out, "Random height: {:.1} cm", height)?;
+
+    //Xorshift is another generator behind the same trait
+    let mut xorshift = Xorshift64::seed(seed);
+    writeln!(out, "Xorshift dice roll: {}", xorshift.gen_range(1..7))?;
+    Ok(())
+}
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    demo(42, out)
+}
+
+pub struct RandomNumbers;
+
+impl bootcamp_core::Lesson for RandomNumbers {
+    fn dir(&self) -> &'static str {
+        "21_Random_Numbers"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use bootcamp_core::input;
+use bootcamp_core::output::Table;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use random_numbers::rng::{Pcg32, RandCrate, Rng, Xorshift64};
+use random_numbers::stats;
+use std::time::Instant;
+
+//`cargo run -- [seed]` for a demo, `cargo run -- chi-square [seed]` to test our generators,
+//`cargo run -- compare [seed]` to put them next to the `rand` crate. The seed defaults to 42.
+fn main() {
+    let args = input::args();
+    let mode = args.first().map(|s| s.as_str()).filter(|m| ["chi-square", "compare"].contains(m));
+    //the seed comes after the mode, or first if there is no mode
+    let seed_index = if mode.is_some() { 1 } else { 0 };
+    let seed: u64 = match input::arg_or(&args, seed_index, 42) {
+        Ok(seed) => seed,
+        Err(e) => {
+            eprintln!("Invalid seed: {}", e);
+            return;
+        }
+    };
+
+    match mode {
+        Some("chi-square") => chi_square(seed),
+        Some("compare") => compare(seed),
+        _ => random_numbers::demo(seed, &mut std::io::stdout()).unwrap(),
+    }
+}
+
+fn print_results(table: &mut Table, name: &str, results: &[stats::TestResult]) {
+    for r in results {
+        table.row(&[
+            name.to_string(),
+            r.name.to_string(),
+            format!("{:.3}", r.statistic),
+            format!("{:.3}", r.critical),
+            String::from(if r.passed() { "PASS" } else { "FAIL" }),
+        ]);
+    }
+}
+
+fn results_table() -> Table {
+    Table::new(&["Generator", "Test", "Chi2", "Critical", "Result"])
+}
+
+fn chi_square(seed: u64) {
+    let samples = 200_000;
+    let mut table = results_table();
+    print_results(&mut table, "xorshift64*", &stats::run_all(&mut Xorshift64::seed(seed), samples));
+    print_results(&mut table, "pcg32", &stats::run_all(&mut Pcg32::seed(seed), samples));
+    table.write_to(&mut std::io::stdout()).unwrap();
+}
+
+//Runs the same statistical tests and a speed test on our generators and on rand's StdRng
+fn compare(seed: u64) {
+    let samples = 200_000;
+    let mut table = results_table();
+    print_results(&mut table, "xorshift64*", &stats::run_all(&mut Xorshift64::seed(seed), samples));
+    print_results(&mut table, "pcg32", &stats::run_all(&mut Pcg32::seed(seed), samples));
+    print_results(
+        &mut table,
+        "rand StdRng",
+        &stats::run_all(&mut RandCrate(StdRng::seed_from_u64(seed)), samples),
+    );
+    table.write_to(&mut std::io::stdout()).unwrap();
+
+    println!();
+    time_generator("xorshift64*", &mut Xorshift64::seed(seed));
+    time_generator("pcg32", &mut Pcg32::seed(seed));
+    time_generator("rand StdRng", &mut RandCrate(StdRng::seed_from_u64(seed)));
+}
+
+fn time_generator(name: &str, rng: &mut impl Rng) {
+    let count = 10_000_000;
+    let start = Instant::now();
+    let mut sink: u64 = 0;
+    for _ in 0..count {
+        sink ^= rng.next_u64();
+    }
+    let elapsed = start.elapsed();
+    //printing `sink` stops the compiler from optimising the loop away
+    println!(
+        "{:<12} {} numbers in {:?} ({:.2} ns each, checksum {:x})",
+        name,
+        count,
+        elapsed,
+        elapsed.as_nanos() as f64 / count as f64,
+        sink
+    );
+}
@@ -0,0 +1,175 @@
+use std::ops::Range;
+
+//Every generator only has to produce 64 random bits at a time, everything else
+//(ranges, floats, shuffling, weighted choice, gaussian) is built on top of next_u64
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32 //the high bits are usually the better ones
+    }
+
+    //Uniform float in [0, 1). Only 53 bits fit in the mantissa of an f64, so we drop the rest.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    //Uniform integer in [0, n) without modulo bias (Lemire's method).
+    //`x % n` would make small numbers slightly more likely whenever n doesn't divide 2^64,
+    //so values that land in the uneven leftover part are thrown away and drawn again.
+    fn gen_below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "gen_below called with n = 0");
+        let mut m = self.next_u64() as u128 * n as u128;
+        if (m as u64) < n {
+            let threshold = n.wrapping_neg() % n;
+            while (m as u64) < threshold {
+                m = self.next_u64() as u128 * n as u128;
+            }
+        }
+        (m >> 64) as u64
+    }
+
+    //Uniform integer in `range`, e.g. rng.gen_range(1..7) for a dice roll
+    fn gen_range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "gen_range called with an empty range");
+        let span = range.end.wrapping_sub(range.start) as u64;
+        range.start.wrapping_add(self.gen_below(span) as i64)
+    }
+
+    //Fisher-Yates: every permutation is equally likely
+    fn shuffle<T>(&mut self, items: &mut [T])
+    where
+        Self: Sized,
+    {
+        for i in (1..items.len()).rev() {
+            let j = self.gen_below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+
+    //Picks an item with probability proportional to its weight.
+    //Returns None if the lengths differ, a weight is negative/NaN or all weights are zero.
+    fn choose_weighted<'a, T>(&mut self, items: &'a [T], weights: &[f64]) -> Option<&'a T>
+    where
+        Self: Sized,
+    {
+        if items.len() != weights.len() || weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return None;
+        }
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut target = self.next_f64() * total;
+        for (item, weight) in items.iter().zip(weights) {
+            if target < *weight {
+                return Some(item);
+            }
+            target -= weight;
+        }
+        //floating point rounding can leave a tiny bit of `target` over, fall back to the last non-zero weight
+        items.iter().zip(weights).rev().find(|(_, w)| **w > 0.0).map(|(item, _)| item)
+    }
+
+    //Normal distribution via the Box-Muller transform
+    fn gaussian(&mut self, mean: f64, std_dev: f64) -> f64 {
+        let u1 = 1.0 - self.next_f64(); //(0, 1], ln(0) would be -infinity
+        let u2 = self.next_f64();
+        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        mean + std_dev * z
+    }
+}
+
+//SplitMix64 turns any seed (including 0) into well mixed state for the other generators
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+//xorshift64*: three shifts and a multiply. Tiny and fast, the state must never be 0.
+pub struct Xorshift64 {
+    state: u64,
+}
+
+impl Xorshift64 {
+    pub fn seed(seed: u64) -> Xorshift64 {
+        let mut s = seed;
+        let mut state = splitmix64(&mut s);
+        if state == 0 {
+            state = 0x9E37_79B9_7F4A_7C15;
+        }
+        Xorshift64 { state }
+    }
+}
+
+impl Rng for Xorshift64 {
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+//PCG32 (XSH RR variant): a linear congruential generator whose output is scrambled by a
+//data-dependent rotation. Produces 32 bits per step.
+pub struct Pcg32 {
+    state: u64,
+    increment: u64, //must be odd
+}
+
+const PCG_MULTIPLIER: u64 = 6364136223846793005;
+
+impl Pcg32 {
+    pub fn seed(seed: u64) -> Pcg32 {
+        Pcg32::with_stream(seed, 0xDA3E_39CB_94B9_5BDB)
+    }
+
+    //Different streams give independent sequences for the same seed
+    pub fn with_stream(seed: u64, stream: u64) -> Pcg32 {
+        let mut rng = Pcg32 {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        rng.step();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.step();
+        rng
+    }
+
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(PCG_MULTIPLIER)
+            .wrapping_add(self.increment);
+    }
+}
+
+impl Rng for Pcg32 {
+    fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.step();
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rotation = (old >> 59) as u32;
+        xorshifted.rotate_right(rotation)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        ((self.next_u32() as u64) << 32) | self.next_u32() as u64
+    }
+}
+
+//Lets the `rand` crate's generators run through the same code, for the comparison mode
+pub struct RandCrate<R: rand::RngCore>(pub R);
+
+impl<R: rand::RngCore> Rng for RandCrate<R> {
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+}
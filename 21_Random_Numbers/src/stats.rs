@@ -0,0 +1,108 @@
+use crate::rng::Rng;
+
+//Pearson's chi-square statistic: how far the observed counts are from the expected ones.
+//`probabilities` must add up to 1 and have the same length as `observed`.
+pub fn chi_square(observed: &[u64], probabilities: &[f64]) -> f64 {
+    let total: u64 = observed.iter().sum();
+    observed
+        .iter()
+        .zip(probabilities)
+        .map(|(&o, &p)| {
+            let expected = total as f64 * p;
+            (o as f64 - expected).powi(2) / expected
+        })
+        .sum()
+}
+
+//Critical value at the 99.9% level for `df` degrees of freedom (Wilson-Hilferty approximation).
+//A good generator stays below it 999 times out of 1000.
+pub fn critical_value(df: usize) -> f64 {
+    let df = df as f64;
+    let z = 3.090_232; //99.9% quantile of the standard normal distribution
+    let term = 2.0 / (9.0 * df);
+    df * (1.0 - term + z * term.sqrt()).powi(3)
+}
+
+pub struct TestResult {
+    pub name: &'static str,
+    pub statistic: f64,
+    pub critical: f64,
+}
+
+impl TestResult {
+    fn new(name: &'static str, observed: &[u64], probabilities: &[f64]) -> TestResult {
+        TestResult {
+            name,
+            statistic: chi_square(observed, probabilities),
+            critical: critical_value(observed.len() - 1),
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.statistic < self.critical
+    }
+}
+
+//Is every value of gen_below(10) equally likely?
+pub fn uniform_test(rng: &mut impl Rng, samples: usize) -> TestResult {
+    let mut counts = [0u64; 10];
+    for _ in 0..samples {
+        counts[rng.gen_below(10) as usize] += 1;
+    }
+    TestResult::new("uniform 0..10", &counts, &[0.1; 10])
+}
+
+//Is every ordering of [0, 1, 2] equally likely after a shuffle?
+pub fn shuffle_test(rng: &mut impl Rng, samples: usize) -> TestResult {
+    let mut counts = [0u64; 6];
+    for _ in 0..samples {
+        let mut items = [0usize, 1, 2];
+        rng.shuffle(&mut items);
+        //turn the permutation into an index 0..6
+        let index = items[0] * 2 + if items[1] < items[2] { 0 } else { 1 };
+        counts[index] += 1;
+    }
+    TestResult::new("shuffle of 3", &counts, &[1.0 / 6.0; 6])
+}
+
+//Are items picked in proportion to weights 1:2:3:4?
+pub fn weighted_test(rng: &mut impl Rng, samples: usize) -> TestResult {
+    let items = [0usize, 1, 2, 3];
+    let weights = [1.0, 2.0, 3.0, 4.0];
+    let mut counts = [0u64; 4];
+    for _ in 0..samples {
+        if let Some(&i) = rng.choose_weighted(&items, &weights) {
+            counts[i] += 1;
+        }
+    }
+    TestResult::new("weighted 1:2:3:4", &counts, &[0.1, 0.2, 0.3, 0.4])
+}
+
+//Do standard normal samples fall below -1, in [-1, 0), [0, 1) and above 1 as often as they should?
+pub fn gaussian_test(rng: &mut impl Rng, samples: usize) -> TestResult {
+    let mut counts = [0u64; 4];
+    for _ in 0..samples {
+        let x = rng.gaussian(0.0, 1.0);
+        let bucket = if x < -1.0 {
+            0
+        } else if x < 0.0 {
+            1
+        } else if x < 1.0 {
+            2
+        } else {
+            3
+        };
+        counts[bucket] += 1;
+    }
+    let tail = 0.158_655_25; //P(X < -1) for a standard normal
+    TestResult::new("gaussian", &counts, &[tail, 0.5 - tail, 0.5 - tail, tail])
+}
+
+pub fn run_all(rng: &mut impl Rng, samples: usize) -> Vec<TestResult> {
+    vec![
+        uniform_test(rng, samples),
+        shuffle_test(rng, samples),
+        weighted_test(rng, samples),
+        gaussian_test(rng, samples),
+    ]
+}
@@ -0,0 +1,116 @@
+use random_numbers::rng::{Pcg32, RandCrate, Rng, Xorshift64};
+
+fn first_numbers(rng: &mut impl Rng) -> Vec<u64> {
+    (0..20).map(|_| rng.next_u64()).collect()
+}
+
+#[test]
+fn same_seed_same_sequence() {
+    assert_eq!(first_numbers(&mut Pcg32::seed(42)), first_numbers(&mut Pcg32::seed(42)));
+    assert_eq!(first_numbers(&mut Xorshift64::seed(42)), first_numbers(&mut Xorshift64::seed(42)));
+    assert_ne!(first_numbers(&mut Pcg32::seed(42)), first_numbers(&mut Pcg32::seed(43)));
+    assert_ne!(first_numbers(&mut Xorshift64::seed(42)), first_numbers(&mut Xorshift64::seed(43)));
+    assert_ne!(
+        first_numbers(&mut Pcg32::with_stream(42, 1)),
+        first_numbers(&mut Pcg32::with_stream(42, 2))
+    );
+}
+
+#[test]
+fn pcg32_matches_the_reference_implementation() {
+    //pcg32_srandom_r(42, 54) from the PCG paper's demo program
+    let mut rng = Pcg32::with_stream(42, 54);
+    let first: Vec<u32> = (0..6).map(|_| rng.next_u32()).collect();
+    assert_eq!(first, [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e]);
+}
+
+#[test]
+fn seed_zero_still_works() {
+    let numbers = first_numbers(&mut Xorshift64::seed(0));
+    assert!(numbers.iter().all(|&n| n != 0));
+    assert_ne!(numbers[0], numbers[1]);
+}
+
+#[test]
+fn shuffle_is_a_permutation() {
+    let mut rng = Pcg32::seed(7);
+    for len in [0, 1, 2, 10, 100] {
+        let original: Vec<u32> = (0..len).collect();
+        let mut shuffled = original.clone();
+        rng.shuffle(&mut shuffled);
+        let mut sorted = shuffled.clone();
+        sorted.sort();
+        assert_eq!(sorted, original);
+        if len >= 10 {
+            assert_ne!(shuffled, original); //1 in 3.6 million for 10 items, and the seed is fixed
+        }
+    }
+}
+
+#[test]
+fn range_reaches_both_ends_and_never_the_end() {
+    let mut rng = Xorshift64::seed(1);
+    let rolls: Vec<i64> = (0..10_000).map(|_| rng.gen_range(-3..3)).collect();
+    assert!(rolls.iter().all(|r| (-3..3).contains(r)));
+    assert!(rolls.contains(&-3));
+    assert!(rolls.contains(&2));
+
+    assert!((0..100).all(|_| rng.gen_range(5..6) == 5));
+    //the widest possible range mustn't overflow
+    for _ in 0..100 {
+        rng.gen_range(i64::MIN..i64::MAX);
+    }
+    assert!((0..100).all(|_| rng.gen_below(1) == 0));
+}
+
+#[test]
+fn below_has_no_modulo_bias() {
+    //n = 3 * 2^62. With `next_u64() % n` the numbers below 2^64 - n = 2^62 come up twice as
+    //often as the rest, so a third of the range would get half of the results.
+    let n = 3u64 << 62;
+    let mut rng = Pcg32::seed(42);
+    let samples = 30_000;
+    let low = (0..samples).filter(|_| rng.gen_below(n) < n / 3).count();
+    let fraction = low as f64 / samples as f64;
+    assert!((fraction - 1.0 / 3.0).abs() < 0.02, "{} of the results in the first third", fraction);
+
+    //same at the top end: the last third gets a third, not a sixth
+    let high = (0..samples).filter(|_| rng.gen_below(n) >= n / 3 * 2).count();
+    let fraction = high as f64 / samples as f64;
+    assert!((fraction - 1.0 / 3.0).abs() < 0.02, "{} of the results in the last third", fraction);
+}
+
+#[test]
+fn floats_stay_in_zero_to_one() {
+    let mut rng = Pcg32::seed(3);
+    assert!((0..10_000).map(|_| rng.next_f64()).all(|f| (0.0..1.0).contains(&f)));
+}
+
+#[test]
+fn weighted_choice_rejects_bad_weights() {
+    let mut rng = Pcg32::seed(42);
+    let items = ["a", "b"];
+    assert_eq!(rng.choose_weighted(&items, &[1.0]), None);
+    assert_eq!(rng.choose_weighted(&items, &[1.0, -1.0]), None);
+    assert_eq!(rng.choose_weighted(&items, &[1.0, f64::NAN]), None);
+    assert_eq!(rng.choose_weighted(&items, &[0.0, 0.0]), None);
+    assert!((0..100).all(|_| rng.choose_weighted(&items, &[0.0, 3.0]) == Some(&"b")));
+}
+
+#[test]
+fn gaussian_moments() {
+    let mut rng = Xorshift64::seed(42);
+    let samples: Vec<f64> = (0..50_000).map(|_| rng.gaussian(170.0, 10.0)).collect();
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+    assert!((mean - 170.0).abs() < 0.2, "mean {}", mean);
+    assert!((variance.sqrt() - 10.0).abs() < 0.2, "standard deviation {}", variance.sqrt());
+}
+
+#[test]
+fn rand_crate_adapter() {
+    use rand::SeedableRng;
+    let mut a = RandCrate(rand::rngs::StdRng::seed_from_u64(9));
+    let mut b = RandCrate(rand::rngs::StdRng::seed_from_u64(9));
+    assert_eq!(first_numbers(&mut a), first_numbers(&mut b));
+}
@@ -0,0 +1,55 @@
+use random_numbers::rng::{Pcg32, Rng, Xorshift64};
+use random_numbers::stats::{chi_square, critical_value, run_all, uniform_test};
+
+#[test]
+fn chi_square_of_a_perfect_match_is_zero() {
+    assert_eq!(chi_square(&[25, 25, 25, 25], &[0.25; 4]), 0.0);
+    //(60 - 50)^2 / 50 + (40 - 50)^2 / 50
+    assert_eq!(chi_square(&[60, 40], &[0.5, 0.5]), 4.0);
+}
+
+#[test]
+fn critical_values_match_the_tables() {
+    //from a chi-square table at p = 0.001. The approximation is a little high for so few
+    //degrees of freedom, but within 2%.
+    for (df, expected) in [(3, 16.266), (5, 20.515), (9, 27.877), (99, 148.230)] {
+        let error = (critical_value(df) - expected).abs() / expected;
+        assert!(error < 0.02, "df {}: {} instead of {}", df, critical_value(df), expected);
+    }
+}
+
+#[test]
+fn our_generators_pass_for_a_fixed_seed() {
+    for results in [run_all(&mut Xorshift64::seed(42), 50_000), run_all(&mut Pcg32::seed(42), 50_000)] {
+        assert_eq!(results.len(), 4);
+        for r in results {
+            assert!(r.passed(), "{}: {} >= {}", r.name, r.statistic, r.critical);
+        }
+    }
+}
+
+//Always returns the same number, about as bad as a generator gets.
+//(Not a round number like 1 << 63: gen_below would reject that one forever and never return.)
+struct Stuck;
+
+impl Rng for Stuck {
+    fn next_u64(&mut self) -> u64 {
+        0x9E37_79B9_7F4A_7C15
+    }
+}
+
+//Counts up by one, every value is different but the high bits barely change
+struct Counter(u64);
+
+impl Rng for Counter {
+    fn next_u64(&mut self) -> u64 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+#[test]
+fn broken_generators_fail() {
+    assert!(run_all(&mut Stuck, 10_000).iter().all(|r| !r.passed()));
+    assert!(!uniform_test(&mut Counter(0), 10_000).passed());
+}
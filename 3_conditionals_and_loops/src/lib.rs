@@ -0,0 +1,51 @@
+use std::io::{self, Write};
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    let x = 99;
+    let is_even = is_even(x);
+    if is_even {
+        writeln!(out, "{} is even", x)?;
+    } else {
+        writeln!(out, "{} is odd", x)?;
+    }
+
+    for i in 0..10 {
+        //runs for 0 to 9
+        write!(out, "{} ", i)?;
+    }
+
+    let sentence = String::from("My Name is Curator");
+    let first_word = get_first_word(sentence);
+    writeln!(out, "\nFirst word: {}", first_word)?;
+    Ok(())
+}
+
+fn is_even(num: i32) -> bool {
+    num % 2 == 0
+}
+
+fn get_first_word(sentence: String) -> String {
+    // let ans = String::new()
+    //or
+    let mut ans = String::from("");
+    for char in sentence.chars() {
+        ans.push_str(char.to_string().as_str());
+        if char == ' ' {
+            break;
+        }
+    }
+    return ans;
+}
+
+pub struct ConditionalsAndLoops;
+
+impl bootcamp_core::Lesson for ConditionalsAndLoops {
+    fn dir(&self) -> &'static str {
+        "3_conditionals_and_loops"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use std::io::{self, Write};
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    let a = 5;
+    let b = 10;
+
+    // Call the function to sum a and b
+    let result = do_sum(a, b);
+
+    // Print the result
+    writeln!(out, "The sum of {} and {} is: {}", a, b, result)?;
+    Ok(())
+}
+
+fn do_sum(a: i32, b: i32) -> i32 { //We need to define return type explicity , it can't be infered automatically like in TypeScript
+    // a + b
+    return a + b;
+}
+
+pub struct Functions;
+
+impl bootcamp_core::Lesson for Functions {
+    fn dir(&self) -> &'static str {
+        "4_Functions"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use std::io::{self, Write};
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    stack_fn(out)?;   // Call the function that uses stack memory
+    heap_fn(out)?;    // Call the function that uses heap memory
+    update_string(out)?;  // Call the function that changes size of variable at runtime
+    Ok(())
+}
+
+fn stack_fn(out: &mut impl Write) -> io::Result<()> {
+    // Declare a few integers on the stack
+    let a = 10;
+    let b = 20;
+    let c = a + b;
+    writeln!(out, "Stack function: The sum of {} and {} is {}", a, b, c)?;
+    Ok(())
+}
+
+fn heap_fn(out: &mut impl Write) -> io::Result<()> {
+    // Create a string, which is allocated on the heap
+    let s1 = String::from("Hello");
+    let s2 = String::from("World");
+    let combined = format!("{} {}", s1, s2);
+    writeln!(out, "Heap function: Combined string is '{}'", combined)?;
+    Ok(())
+}
+
+fn update_string(out: &mut impl Write) -> io::Result<()> {
+    //Note: In the heap, we might have to reallocate the pointer if the size changes beyond its current capacity or there's no contiguous space available at the current location.
+    //Also, if rust notices that the string is updated very frequently, it might optimize the memory allocation strategy and allocate more space than usually needed.
+
+    
+    // Start with a base string on the heap
+    let mut s = String::from("Initial string");
+    writeln!(out, "Before update: {}", s)?;
+    writeln!(out, "Capacity:{}, Length:{}, Pointer:{:p}", s.capacity(), s.len(), s.as_ptr())?;
+    
+    // Append some text to the string
+    s.push_str(" and some additional text");
+    writeln!(out, "After update: {}", s)?;
+    writeln!(out, "Capacity:{}, Length:{}, Pointer:{:p}", s.capacity(), s.len(), s.as_ptr())?;
+    Ok(())
+}
+
+pub struct StackAndHeap;
+
+impl bootcamp_core::Lesson for StackAndHeap {
+    fn dir(&self) -> &'static str {
+        "5_Stack-and-Heap"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+use bootcamp_core::snapshot::{assert_snapshot, redact_after};
+
+#[test]
+fn output_matches_snapshot() {
+    let mut out = Vec::new();
+    stack_and_heap::run(&mut out).unwrap();
+    let mut output = String::from_utf8(out).unwrap();
+    //heap addresses change on every run
+    output = redact_after(&output, "Pointer:");
+    assert_snapshot(env!("CARGO_MANIFEST_DIR"), "output", &output);
+}
@@ -0,0 +1,23 @@
+use std::io::{self, Write};
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    let s1 = String::from("hello world");
+    writeln!(out, "{}", s1)?; // This works, s1 is valid here(not yet moved)
+    let s2 = s1;
+    // println!("{}", s1); // error: value borrowed here after move
+    writeln!(out, "{}", s2)?; // This would work, but s1 is no longer valid
+    Ok(())
+}
+
+pub struct Ownership;
+
+impl bootcamp_core::Lesson for Ownership {
+    fn dir(&self) -> &'static str {
+        "6_Ownership"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+pub fn use_after_move() {
+    let s1 = String::from("hello world");
+    let s2 = s1; //ownership of the heap data moves to s2
+    println!("{}", s1); //ERR: E0382 borrow of moved value: `s1`
+    println!("{}", s2);
+}
@@ -0,0 +1,3 @@
+pub fn update_str(s: &String) {
+    s.push_str("World"); //ERR: E0596 cannot borrow `*s` as mutable, as it is behind a `&` reference
+}
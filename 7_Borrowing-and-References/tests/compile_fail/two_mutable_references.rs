@@ -0,0 +1,10 @@
+fn update_str(s: &mut String) {
+    s.push_str(" World");
+}
+
+pub fn two_mutable_references() {
+    let mut s1 = String::from("Hello");
+    let s2 = &mut s1;
+    update_str(&mut s1); //ERR: E0499 You can only have one mutable reference to a piece of data in a particular scope
+    println!("{}", s2);
+}
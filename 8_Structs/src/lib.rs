@@ -0,0 +1,54 @@
+use std::io::{self, Write};
+
+// struct User{
+//     name: String,
+//     age: u32,
+//     active: bool,
+// }
+// fn main() {
+//     let name = String::from("Ayush");
+
+//     let user = User{
+//         name:name,
+//         age: 20,
+//         active: true,
+//     };
+//     println!("{} is {} years old.", user.name, user.age);
+// }
+
+struct Rect {
+    width: u32,
+    height: u32,
+}
+
+impl Rect { //implementation of struct Rect
+    fn area(&self) -> u32 {
+        self.width * self.height
+    }
+    fn perimeter(&self) -> u32 {
+        2 * (self.width + self.height)
+    }
+}
+
+pub fn run(out: &mut impl Write) -> io::Result<()> {
+    let rect = Rect {
+        width: 30,
+        height: 50,
+    };
+    write!(out, "The area of the rectangle is {}", rect.area())?;
+    writeln!(out, " and the perimeter is {}", rect.perimeter())?;
+    Ok(())
+}
+
+pub struct Structs;
+
+impl bootcamp_core::Lesson for Structs {
+    fn dir(&self) -> &'static str {
+        "8_Structs"
+    }
+
+    fn run(&self, mut out: &mut dyn Write) -> bootcamp_core::Result<()> {
+        run(&mut out)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+//Anything that can tell the current time. Code that asks a Clock instead of calling Instant::now()
+//itself (the stopwatch in 11_Package_Management, the TTL cache in 12_Collections) can be tested
+//with a ManualClock that is moved forward by hand, no sleeping and no flaky timing.
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+//A clock that only moves when `advance` is called
+pub struct ManualClock {
+    start: Instant,
+    offset: Cell<Duration>,
+}
+
+impl ManualClock {
+    pub fn new() -> ManualClock {
+        ManualClock {
+            start: Instant::now(),
+            offset: Cell::new(Duration::ZERO),
+        }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.offset.set(self.offset.get() + by);
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        ManualClock::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.start + self.offset.get()
+    }
+}
+
+//So a clock can be lent out and still advanced by the test that owns it
+impl<C: Clock> Clock for &C {
+    fn now(&self) -> Instant {
+        (*self).now()
+    }
+}
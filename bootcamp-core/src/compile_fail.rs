@@ -0,0 +1,77 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+//Compiles every file in the lesson's `tests/compile_fail/` folder with rustc and checks that it
+//fails with exactly the error codes listed in its `//ERR: E0382` comments. This keeps the
+//errors documented in the lessons true on newer compilers, without depending on the exact
+//wording of the messages. `manifest_dir` is the lesson's folder, pass `env!("CARGO_MANIFEST_DIR")`.
+pub fn assert_compile_errors(manifest_dir: &str) {
+    let dir = Path::new(manifest_dir).join("tests").join("compile_fail");
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", dir.display(), e))
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    files.sort();
+    assert!(!files.is_empty(), "no examples in {}", dir.display());
+
+    let failures: Vec<String> = files.iter().filter_map(|file| check(file).err()).collect();
+    if !failures.is_empty() {
+        panic!("{} compile-fail example(s) failed:\n\n{}", failures.len(), failures.join("\n\n"));
+    }
+}
+
+//Error codes such as E0382 that appear in `text` after `marker`
+fn error_codes(text: &str, marker: &str) -> Vec<String> {
+    let mut codes: Vec<String> = text
+        .match_indices(marker)
+        .filter_map(|(i, _)| {
+            let rest = &text[i + marker.len()..];
+            let code = rest.trim_start().get(..5)?;
+            let is_code = code.starts_with('E') && code[1..].chars().all(|c| c.is_ascii_digit());
+            is_code.then(|| code.to_string())
+        })
+        .collect();
+    codes.sort();
+    codes.dedup();
+    codes
+}
+
+fn check(file: &Path) -> Result<(), String> {
+    let name = file.file_stem().unwrap().to_string_lossy().to_string();
+    let source = fs::read_to_string(file).map_err(|e| format!("{}: {}", name, e))?;
+    let expected = error_codes(&source, "//ERR:");
+    if expected.is_empty() {
+        return Err(format!("{}: no `//ERR: E....` comment saying which error to expect", name));
+    }
+
+    let out_dir = std::env::temp_dir().join(format!("bootcamp-compile-fail-{}-{}", std::process::id(), name));
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    let output = Command::new(rustc)
+        .args(["--edition", "2024", "--crate-type", "lib", "--emit", "metadata"])
+        .arg("--crate-name")
+        .arg(name.replace('-', "_"))
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .arg(file)
+        .output()
+        .map_err(|e| format!("{}: could not run rustc: {}", name, e))?;
+    let _ = fs::remove_dir_all(&out_dir);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if output.status.success() {
+        return Err(format!("{}: compiled, but expected {}", name, expected.join(", ")));
+    }
+    let actual = error_codes(&stderr, "error[");
+    if actual != expected {
+        return Err(format!(
+            "{}: expected {} but rustc reported {}\n{}",
+            name,
+            expected.join(", "),
+            if actual.is_empty() { String::from("no error codes") } else { actual.join(", ") },
+            stderr
+        ));
+    }
+    Ok(())
+}
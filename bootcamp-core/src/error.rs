@@ -0,0 +1,39 @@
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum BootcampError {
+    Io(io::Error),
+    Parse { input: String, message: String }, //the text we tried to parse and why it failed
+    Usage(String),                            //the program was called with wrong arguments
+}
+
+impl fmt::Display for BootcampError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BootcampError::Io(e) => write!(f, "I/O error: {}", e),
+            BootcampError::Parse { input, message } => {
+                write!(f, "could not parse {:?}: {}", input, message)
+            }
+            BootcampError::Usage(message) => write!(f, "usage: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for BootcampError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BootcampError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for BootcampError {
+    fn from(e: io::Error) -> Self {
+        BootcampError::Io(e)
+    }
+}
+
+//Shorthand so lessons can write `Result<T>` instead of `Result<T, BootcampError>`
+pub type Result<T> = std::result::Result<T, BootcampError>;
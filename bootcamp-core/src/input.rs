@@ -0,0 +1,51 @@
+use crate::error::{BootcampError, Result};
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+//Parses a trimmed string into any type that implements FromStr, keeping the input in the error
+pub fn parse<T>(input: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input.trim().parse().map_err(|e: T::Err| BootcampError::Parse {
+        input: input.to_string(),
+        message: e.to_string(),
+    })
+}
+
+//Command line arguments without the program name
+pub fn args() -> Vec<String> {
+    std::env::args().skip(1).collect()
+}
+
+//Parses args[index], or returns `default` when the argument was not given
+pub fn arg_or<T>(args: &[String], index: usize, default: T) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    match args.get(index) {
+        Some(arg) => parse(arg),
+        None => Ok(default),
+    }
+}
+
+//Reads one line from `reader` with the trailing newline removed. Returns None at end of input.
+pub fn read_line(reader: &mut impl BufRead) -> Result<Option<String>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    let trimmed = line.trim_end_matches(['\n', '\r']).len();
+    line.truncate(trimmed);
+    Ok(Some(line))
+}
+
+//Prints `message` and waits for the user to type a line on stdin
+pub fn prompt(message: &str) -> Result<Option<String>> {
+    print!("{}", message);
+    io::stdout().flush()?;
+    read_line(&mut io::stdin().lock())
+}
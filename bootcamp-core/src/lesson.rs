@@ -0,0 +1,11 @@
+use crate::error::Result;
+use std::io::Write;
+
+//Every lesson crate implements this so the `bootcamp` launcher can run it in-process
+pub trait Lesson {
+    //Folder of the lesson inside the repo, e.g. "20_Channels"
+    fn dir(&self) -> &'static str;
+
+    //Everything the lesson prints goes to `out`, so callers can show it or capture it
+    fn run(&self, out: &mut dyn Write) -> Result<()>;
+}
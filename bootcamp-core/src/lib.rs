@@ -0,0 +1,14 @@
+//Shared code for the lessons: reading input, printing output, one error type for both, a mockable clock,
+//the golden-file helper behind every lesson's snapshot test and the compile-fail checker.
+//Lessons add it with `bootcamp-core = { path = "../bootcamp-core" }` and use it as `bootcamp_core`.
+
+pub mod clock;
+pub mod compile_fail;
+pub mod error;
+pub mod input;
+pub mod lesson;
+pub mod output;
+pub mod snapshot;
+
+pub use error::{BootcampError, Result};
+pub use lesson::Lesson;
@@ -0,0 +1,71 @@
+use std::io::{self, Write};
+
+//A plain text table with right aligned columns, sized to fit the widest cell:
+//
+//  Lap |     Split
+// -----+----------
+//    1 | 00:01.200
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: ToString>(headers: &[S]) -> Table {
+        Table {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    //Missing cells are left blank, extra cells are ignored
+    pub fn row<S: ToString>(&mut self, cells: &[S]) -> &mut Table {
+        self.rows.push(cells.iter().map(|c| c.to_string()).collect());
+        self
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        self.headers
+            .iter()
+            .enumerate()
+            .map(|(i, header)| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(i))
+                    .map(|cell| cell.chars().count())
+                    .chain([header.chars().count()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    pub fn write_to(&self, out: &mut impl Write) -> io::Result<()> {
+        let widths = self.widths();
+        let line = |cells: &[String]| {
+            widths
+                .iter()
+                .enumerate()
+                .map(|(i, width)| {
+                    let cell = cells.get(i).map_or("", |c| c.as_str());
+                    format!("{:>width$}", cell, width = width)
+                })
+                .collect::<Vec<_>>()
+                .join(" | ")
+        };
+
+        writeln!(out, "{}", line(&self.headers))?;
+        let separator: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        writeln!(out, "{}", separator.join("-+-"))?;
+        for row in &self.rows {
+            writeln!(out, "{}", line(row))?;
+        }
+        Ok(())
+    }
+}
+
+//A title underlined with `=`, used to separate sections of a lesson's output
+pub fn heading(out: &mut impl Write, title: &str) -> io::Result<()> {
+    writeln!(out, "{}", title)?;
+    writeln!(out, "{}", "=".repeat(title.chars().count()))
+}
@@ -0,0 +1,57 @@
+use std::fs;
+use std::path::Path;
+
+//Compares a lesson's output with the golden file `tests/snapshots/<name>.txt` of that lesson.
+//Run the tests with UPDATE_SNAPSHOTS=1 to write the current output as the new golden file.
+//`manifest_dir` is the lesson's folder, pass `env!("CARGO_MANIFEST_DIR")` from the test.
+pub fn assert_snapshot(manifest_dir: &str, name: &str, actual: &str) {
+    let path = Path::new(manifest_dir)
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{}.txt", name));
+
+    if std::env::var("UPDATE_SNAPSHOTS").is_ok_and(|v| v == "1") {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = match fs::read_to_string(&path) {
+        Ok(expected) => expected,
+        Err(e) => panic!(
+            "could not read snapshot {}: {}\nrun with UPDATE_SNAPSHOTS=1 to create it",
+            path.display(),
+            e
+        ),
+    };
+    if expected == actual {
+        return;
+    }
+
+    //point at the first line that differs, the full texts are printed below it
+    let line = expected
+        .lines()
+        .zip(actual.lines())
+        .position(|(e, a)| e != a)
+        .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+    panic!(
+        "output does not match snapshot {} (first difference on line {})\n\
+         --- expected ---\n{}\n--- actual ---\n{}\n\
+         run with UPDATE_SNAPSHOTS=1 if the new output is correct",
+        path.display(),
+        line + 1,
+        expected,
+        actual
+    );
+}
+
+//Replaces the rest of every line after `marker` with `[redacted]`, for output that changes
+//on every run such as the current time or a heap address
+pub fn redact_after(text: &str, marker: &str) -> String {
+    text.lines()
+        .map(|line| match line.find(marker) {
+            Some(i) => format!("{}[redacted]\n", &line[..i + marker.len()]),
+            None => format!("{}\n", line),
+        })
+        .collect()
+}
@@ -0,0 +1,130 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub struct LessonDir {
+    pub number: u32,
+    pub dir: String,
+    pub title: String,
+}
+
+//Words that don't help to match a folder name with a heading
+const FILLER_WORDS: [&str; 7] = ["and", "with", "in", "vs", "the", "of", "a"];
+
+//Lesson folders start with their number: `1_hello-world` ... `20_Channels`
+fn lesson_number(dir: &str) -> Option<u32> {
+    let (number, rest) = dir.split_once('_')?;
+    if rest.is_empty() {
+        return None;
+    }
+    number.parse().ok()
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty() && !w.chars().all(|c| c.is_ascii_digit()))
+        .map(|w| w.to_lowercase())
+        .filter(|w| !FILLER_WORDS.contains(&w.as_str()))
+        .collect()
+}
+
+//"packages" should still match "package", so compare by prefix in both directions
+fn same_word(a: &str, b: &str) -> bool {
+    a.starts_with(b) || b.starts_with(a)
+}
+
+//All markdown headings of NOTES.md, NOTES2.md, ... as (level, text), skipping code blocks
+pub fn headings(root: &Path) -> io::Result<Vec<(usize, String)>> {
+    let mut notes: Vec<String> = fs::read_dir(root)?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name.starts_with("NOTES") && name.ends_with(".md"))
+        .collect();
+    notes.sort();
+
+    let mut headings = Vec::new();
+    for name in notes {
+        let mut in_code = false;
+        for line in fs::read_to_string(root.join(name))?.lines() {
+            //a fence is ``` on its own line (or with a language), not inline ```code``` at the start of a line
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") && !trimmed[3..].contains("```") {
+                in_code = !in_code;
+                continue;
+            }
+            if in_code {
+                continue;
+            }
+            let level = line.chars().take_while(|c| *c == '#').count();
+            if level > 0 && line[level..].starts_with(' ') {
+                headings.push((level, line[level..].trim().to_string()));
+            }
+        }
+    }
+    Ok(headings)
+}
+
+//Picks the `#` or `##` heading that contains every word of the folder name with the fewest
+//extra words, preferring bigger headings. Falls back to the folder name itself, e.g. "Hello World".
+pub fn title_for(dir: &str, headings: &[(usize, String)]) -> String {
+    let name = dir.split_once('_').map_or(dir, |(_, name)| name);
+    let wanted = words(name);
+
+    let best = headings
+        .iter()
+        .filter_map(|(level, text)| {
+            let heading_words = words(text);
+            let all_found = wanted
+                .iter()
+                .all(|w| heading_words.iter().any(|h| same_word(w, h)));
+            if *level > 2 || !all_found || wanted.is_empty() {
+                return None;
+            }
+            Some(((heading_words.len().saturating_sub(wanted.len()), *level), text))
+        })
+        .min_by_key(|(score, _)| *score);
+
+    match best {
+        Some((_, text)) => text.clone(),
+        None => name
+            .split(['_', '-'])
+            .map(|w| {
+                let mut chars = w.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                    None => String::new(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
+//Finds every lesson folder in `root`, sorted by lesson number
+pub fn discover(root: &Path) -> io::Result<Vec<LessonDir>> {
+    let headings = headings(root)?;
+    let mut lessons: Vec<LessonDir> = fs::read_dir(root)?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            if !entry.file_type().ok()?.is_dir() {
+                return None;
+            }
+            let dir = entry.file_name().into_string().ok()?;
+            let number = lesson_number(&dir)?;
+            let title = title_for(&dir, &headings);
+            Some(LessonDir { number, dir, title })
+        })
+        .collect();
+    lessons.sort_by_key(|l| l.number);
+    Ok(lessons)
+}
+
+//A lesson can be picked by number (`20`), folder (`20_Channels`) or name (`channels`)
+pub fn find_dir<'a>(dirs: &'a [LessonDir], selector: &str) -> Option<&'a LessonDir> {
+    let normalize = |s: &str| s.to_lowercase().replace('-', "_");
+    let selector = normalize(selector);
+    dirs.iter().find(|d| {
+        let dir = normalize(&d.dir);
+        let name = dir.split_once('_').map_or(dir.as_str(), |(_, name)| name);
+        d.number.to_string() == selector || dir == selector || name == selector
+    })
+}
@@ -0,0 +1,29 @@
+use bootcamp_core::Lesson;
+
+//Every lesson the launcher can run in-process. A new lesson crate only needs a line here
+//(and a dependency in Cargo.toml), the launcher finds its folder and title on its own.
+pub fn all() -> Vec<Box<dyn Lesson>> {
+    vec![
+        Box::new(hello_world::HelloWorld),
+        Box::new(simple_variables::SimpleVariables),
+        Box::new(conditionals_and_loops::ConditionalsAndLoops),
+        Box::new(functions::Functions),
+        Box::new(stack_and_heap::StackAndHeap),
+        Box::new(ownership::Ownership),
+        Box::new(borrowing_and_references::BorrowingAndReferences),
+        Box::new(structs::Structs),
+        Box::new(enums::Enums),
+        Box::new(error_handling::ErrorHandling),
+        Box::new(package_management::PackageManagement),
+        Box::new(collections::Collections),
+        Box::new(iterators::Iterators),
+        Box::new(strings_and_slices::StringsAndSlices),
+        Box::new(generics::Generics),
+        Box::new(traits::Traits),
+        Box::new(lifetimes::Lifetimes),
+        Box::new(structs_with_lifetimes::StructsWithLifetimes),
+        Box::new(multithreading::Multithreading),
+        Box::new(channels::Channels),
+        Box::new(random_numbers::RandomNumbers),
+    ]
+}
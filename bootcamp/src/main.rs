@@ -0,0 +1,98 @@
+use bootcamp_core::output::Table;
+use bootcamp_core::{BootcampError, Lesson, Result, input};
+use bootcamp::discover::{self, LessonDir, find_dir};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process::ExitCode;
+
+mod lessons;
+
+const USAGE: &str = "bootcamp list | bootcamp run <lesson> | bootcamp run --all";
+
+fn main() -> ExitCode {
+    match launch(&input::args()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+//Returns Ok(false) when a lesson failed, so `run --all` can still print the whole summary first
+fn launch(args: &[String]) -> Result<bool> {
+    //the lessons live next to this crate's folder
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let dirs = discover::discover(&root)?;
+    let lessons = lessons::all();
+
+    match args.iter().map(|a| a.as_str()).collect::<Vec<_>>().as_slice() {
+        [] | ["list"] => {
+            list(&dirs, &lessons);
+            Ok(true)
+        }
+        ["run", "--all"] => Ok(run_all(&dirs, &lessons)),
+        ["run", selector] => {
+            let dir = find_dir(&dirs, selector)
+                .ok_or_else(|| BootcampError::Usage(format!("no lesson matches {:?}", selector)))?;
+            let lesson = find_lesson(&lessons, &dir.dir).ok_or_else(|| {
+                BootcampError::Usage(format!("{} has no runnable Lesson yet", dir.dir))
+            })?;
+            Ok(run_one(dir, lesson))
+        }
+        _ => Err(BootcampError::Usage(String::from(USAGE))),
+    }
+}
+
+fn find_lesson<'a>(lessons: &'a [Box<dyn Lesson>], dir: &str) -> Option<&'a dyn Lesson> {
+    lessons.iter().find(|l| l.dir() == dir).map(|l| l.as_ref())
+}
+
+fn list(dirs: &[LessonDir], lessons: &[Box<dyn Lesson>]) {
+    let mut table = Table::new(&["#", "Lesson", "Title", "Runnable"]);
+    for dir in dirs {
+        let runnable = if find_lesson(lessons, &dir.dir).is_some() { "yes" } else { "no" };
+        table.row(&[dir.number.to_string(), dir.dir.clone(), dir.title.clone(), runnable.to_string()]);
+    }
+    table.write_to(&mut std::io::stdout()).unwrap();
+}
+
+//Runs a lesson, treating both an Err and a panic as a failure
+fn run_one(dir: &LessonDir, lesson: &dyn Lesson) -> bool {
+    println!("=== {} - {} ===", dir.dir, dir.title);
+    match panic::catch_unwind(AssertUnwindSafe(|| lesson.run(&mut std::io::stdout()))) {
+        Ok(Ok(())) => true,
+        Ok(Err(e)) => {
+            eprintln!("{} failed: {}", dir.dir, e);
+            false
+        }
+        Err(_) => {
+            eprintln!("{} panicked", dir.dir);
+            false
+        }
+    }
+}
+
+fn run_all(dirs: &[LessonDir], lessons: &[Box<dyn Lesson>]) -> bool {
+    let mut summary = Table::new(&["#", "Lesson", "Result"]);
+    let mut failed = 0;
+    for dir in dirs {
+        let result = match find_lesson(lessons, &dir.dir) {
+            Some(lesson) => {
+                let passed = run_one(dir, lesson);
+                println!();
+                if !passed {
+                    failed += 1;
+                }
+                if passed { "pass" } else { "FAIL" }
+            }
+            None => "skipped",
+        };
+        summary.row(&[dir.number.to_string(), dir.dir.clone(), result.to_string()]);
+    }
+
+    summary.write_to(&mut std::io::stdout()).unwrap();
+    println!("{} failed", failed);
+    failed == 0
+}
@@ -0,0 +1,83 @@
+use bootcamp::discover::{LessonDir, discover, find_dir, title_for};
+use std::path::Path;
+
+//A few headings the way they appear in NOTES.md ... NOTES4.md
+fn headings() -> Vec<(usize, String)> {
+    [
+        (1, "Jargon 1 - Stack vs Heap"),
+        (2, "Heap allocation in the Stack and Heap example"),
+        (1, "Strings vs Slices"),
+        (2, "MultiThreading in Rust"),
+        (3, "Channels"),
+        (1, "Package Management with Cargo"),
+        (1, "Packages"),
+    ]
+    .into_iter()
+    .map(|(level, text)| (level, text.to_string()))
+    .collect()
+}
+
+#[test]
+fn titles_come_from_the_closest_heading() {
+    let headings = headings();
+    //filler words ("and", "vs") don't count, the heading with the fewest extra words wins
+    assert_eq!(title_for("5_Stack-and-Heap", &headings), "Jargon 1 - Stack vs Heap");
+    assert_eq!(title_for("14_Strings_and_Slices", &headings), "Strings vs Slices");
+    assert_eq!(title_for("19_Multithreading", &headings), "MultiThreading in Rust");
+    //"Packages" is missing "management", but on its own "package" matches it by prefix
+    assert_eq!(title_for("11_Package_Management", &headings), "Package Management with Cargo");
+    assert_eq!(title_for("11_Package", &headings), "Packages");
+}
+
+#[test]
+fn lessons_without_a_heading_use_their_folder_name() {
+    let headings = headings();
+    assert_eq!(title_for("1_hello-world", &headings), "Hello World");
+    //### headings are too small to be a lesson title
+    assert_eq!(title_for("20_Channels", &headings), "Channels");
+    assert_eq!(title_for("22_async_await", &[]), "Async Await");
+}
+
+#[test]
+fn discovers_the_lesson_folders_in_order() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let lessons = discover(&root).unwrap();
+    let numbers: Vec<u32> = lessons.iter().map(|l| l.number).collect();
+    //bootcamp-core, exercises, quiz and target aren't lessons
+    assert_eq!(numbers, (1..=21).collect::<Vec<_>>());
+    assert_eq!(lessons[19].dir, "20_Channels");
+    assert_eq!(lessons[19].title, "Channels");
+}
+
+fn dirs() -> Vec<LessonDir> {
+    ["1_hello-world", "5_Stack-and-Heap", "20_Channels", "21_Random_Numbers"]
+        .into_iter()
+        .map(|dir| LessonDir {
+            number: dir.split_once('_').unwrap().0.parse().unwrap(),
+            dir: dir.to_string(),
+            title: String::new(),
+        })
+        .collect()
+}
+
+#[test]
+fn find_dir_by_number_folder_or_name() {
+    let dirs = dirs();
+    let found = |selector: &str| find_dir(&dirs, selector).map(|d| d.dir.as_str());
+    assert_eq!(found("20"), Some("20_Channels"));
+    assert_eq!(found("20_Channels"), Some("20_Channels"));
+    assert_eq!(found("channels"), Some("20_Channels"));
+    //package names use _ where the folder has -
+    assert_eq!(found("stack_and_heap"), Some("5_Stack-and-Heap"));
+    assert_eq!(found("hello_world"), Some("1_hello-world"));
+    assert_eq!(found("random_numbers"), Some("21_Random_Numbers"));
+    assert_eq!(found("Stack-and-Heap"), Some("5_Stack-and-Heap"));
+}
+
+#[test]
+fn find_dir_unknown_selector() {
+    let dirs = dirs();
+    for selector in ["2", "heap", "22_Async", ""] {
+        assert!(find_dir(&dirs, selector).is_none(), "{:?} should match nothing", selector);
+    }
+}
@@ -0,0 +1,6 @@
+//Assignment 1 from 12_Collections:
+//Write a function that takes a vector and returns a new vector with even numbers only
+
+pub fn filter_even(v: Vec<i32>) -> Vec<i32> {
+    todo!()
+}
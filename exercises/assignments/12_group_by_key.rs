@@ -0,0 +1,10 @@
+use std::collections::HashMap;
+
+//Assignment 2 from 12_Collections:
+//Write a function that takes a vector of tuples (each tuple contains a key and a value) and returns
+//a HashMap where the keys are unique keys from input tuples and values are vectors of all
+//corresponding values associated with each key (in the order they appeared).
+
+pub fn group_by_key(pairs: Vec<(String, i32)>) -> HashMap<String, Vec<i32>> {
+    todo!()
+}
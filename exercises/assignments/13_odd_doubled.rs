@@ -0,0 +1,6 @@
+//Assignment from 13_Iterators:
+//Write the logic to first filter all odd values then double each and create a new vector
+
+pub fn odd_doubled(v: Vec<i32>) -> Vec<i32> {
+    todo!()
+}
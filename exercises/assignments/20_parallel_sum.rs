@@ -0,0 +1,11 @@
+use std::sync::mpsc;
+use std::thread;
+
+//Assignment from 20_Channels:
+//Find the sum from 1 to n (inclusive). Split the work between `threads` threads, let every thread
+//send its partial sum over a channel and add the partial sums up on the receiving side.
+//Remember it's the "multiple producers" and "single consumer" model.
+
+pub fn parallel_sum(n: u64, threads: u64) -> u64 {
+    todo!()
+}
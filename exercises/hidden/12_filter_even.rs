@@ -0,0 +1,15 @@
+#[test]
+fn keeps_only_even_numbers_in_order() {
+    assert_eq!(filter_even(vec![1, 2, 3, 4, 5, 6]), vec![2, 4, 6]);
+}
+
+#[test]
+fn handles_negative_numbers_and_zero() {
+    assert_eq!(filter_even(vec![-4, -3, 0, 7]), vec![-4, 0]);
+}
+
+#[test]
+fn empty_and_all_odd_give_empty_vectors() {
+    assert_eq!(filter_even(vec![]), Vec::<i32>::new());
+    assert_eq!(filter_even(vec![1, 3, 5]), Vec::<i32>::new());
+}
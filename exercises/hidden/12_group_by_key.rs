@@ -0,0 +1,22 @@
+fn pairs(items: &[(&str, i32)]) -> Vec<(String, i32)> {
+    items.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+}
+
+#[test]
+fn groups_values_under_their_key() {
+    let grouped = group_by_key(pairs(&[("a", 1), ("b", 2), ("a", 3)]));
+    assert_eq!(grouped.len(), 2);
+    assert_eq!(grouped["a"], vec![1, 3]);
+    assert_eq!(grouped["b"], vec![2]);
+}
+
+#[test]
+fn keeps_duplicates_and_input_order() {
+    let grouped = group_by_key(pairs(&[("x", 5), ("x", 5), ("x", -1)]));
+    assert_eq!(grouped["x"], vec![5, 5, -1]);
+}
+
+#[test]
+fn empty_input_gives_empty_map() {
+    assert!(group_by_key(Vec::new()).is_empty());
+}
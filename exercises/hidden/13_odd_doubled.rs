@@ -0,0 +1,14 @@
+#[test]
+fn doubles_the_odd_values() {
+    assert_eq!(odd_doubled(vec![1, 2, 3, 4, 5]), vec![2, 6, 10]);
+}
+
+#[test]
+fn negative_odd_numbers_count_too() {
+    assert_eq!(odd_doubled(vec![-3, -2, 0]), vec![-6]);
+}
+
+#[test]
+fn no_odd_values_gives_empty_vector() {
+    assert_eq!(odd_doubled(vec![2, 4]), Vec::<i32>::new());
+}
@@ -0,0 +1,21 @@
+#[test]
+fn matches_the_formula() {
+    for n in [1, 10, 1_000, 1_000_003] {
+        assert_eq!(parallel_sum(n, 4), n * (n + 1) / 2, "n = {}", n);
+    }
+}
+
+#[test]
+fn works_with_more_threads_than_numbers() {
+    assert_eq!(parallel_sum(3, 8), 6);
+}
+
+#[test]
+fn works_with_a_single_thread() {
+    assert_eq!(parallel_sum(100, 1), 5050);
+}
+
+#[test]
+fn sum_up_to_ten_to_the_eight() {
+    assert_eq!(parallel_sum(100_000_000, 8), 5_000_000_050_000_000);
+}
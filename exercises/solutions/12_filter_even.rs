@@ -0,0 +1,5 @@
+//Reference solution, tests/solutions.rs checks it passes the hidden tests
+
+pub fn filter_even(v: Vec<i32>) -> Vec<i32> {
+    v.into_iter().filter(|x| x % 2 == 0).collect()
+}
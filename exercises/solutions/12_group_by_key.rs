@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+//Reference solution, tests/solutions.rs checks it passes the hidden tests
+
+pub fn group_by_key(pairs: Vec<(String, i32)>) -> HashMap<String, Vec<i32>> {
+    let mut grouped: HashMap<String, Vec<i32>> = HashMap::new();
+    for (key, value) in pairs {
+        grouped.entry(key).or_default().push(value);
+    }
+    grouped
+}
@@ -0,0 +1,5 @@
+//Reference solution, tests/solutions.rs checks it passes the hidden tests
+
+pub fn odd_doubled(v: Vec<i32>) -> Vec<i32> {
+    v.into_iter().filter(|x| x % 2 != 0).map(|x| x * 2).collect()
+}
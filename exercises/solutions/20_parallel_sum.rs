@@ -0,0 +1,19 @@
+use std::sync::mpsc;
+use std::thread;
+
+//Reference solution, tests/solutions.rs checks it passes the hidden tests
+
+pub fn parallel_sum(n: u64, threads: u64) -> u64 {
+    let (tx, rx) = mpsc::channel();
+    let chunk = n.div_ceil(threads);
+    for t in 0..threads {
+        let tx = tx.clone();
+        let start = t * chunk + 1;
+        let end = n.min((t + 1) * chunk);
+        thread::spawn(move || {
+            tx.send((start..=end).sum::<u64>()).unwrap();
+        });
+    }
+    drop(tx); //otherwise the receiver waits for our sender forever
+    rx.iter().sum()
+}
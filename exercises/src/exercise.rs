@@ -0,0 +1,132 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub struct Exercise {
+    pub id: &'static str,     //file name in assignments/ without .rs
+    pub lesson: &'static str, //lesson folder the assignment comes from
+    hidden_tests: &'static str,
+}
+
+//The hidden tests are compiled into the runner, so editing assignments/ can't change them
+pub const EXERCISES: [Exercise; 4] = [
+    Exercise {
+        id: "12_filter_even",
+        lesson: "12_Collections",
+        hidden_tests: include_str!("../hidden/12_filter_even.rs"),
+    },
+    Exercise {
+        id: "12_group_by_key",
+        lesson: "12_Collections",
+        hidden_tests: include_str!("../hidden/12_group_by_key.rs"),
+    },
+    Exercise {
+        id: "13_odd_doubled",
+        lesson: "13_Iterators",
+        hidden_tests: include_str!("../hidden/13_odd_doubled.rs"),
+    },
+    Exercise {
+        id: "20_parallel_sum",
+        lesson: "20_Channels",
+        hidden_tests: include_str!("../hidden/20_parallel_sum.rs"),
+    },
+];
+
+pub enum Status {
+    NotStarted,               //still has the todo!() from the stub
+    DoesNotCompile(String),   //rustc output
+    Failing(String),          //test output
+    Done,
+}
+
+impl Status {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::NotStarted => "not started",
+            Status::DoesNotCompile(_) => "does not compile",
+            Status::Failing(_) => "failing",
+            Status::Done => "done",
+        }
+    }
+
+    pub fn details(&self) -> Option<&str> {
+        match self {
+            Status::DoesNotCompile(output) | Status::Failing(output) => Some(output),
+            _ => None,
+        }
+    }
+}
+
+impl Exercise {
+    pub fn path(&self, root: &Path) -> PathBuf {
+        root.join("assignments").join(format!("{}.rs", self.id))
+    }
+
+    //Builds the assignment together with its hidden tests using `rustc --test` and runs them
+    pub fn check(&self, root: &Path) -> Status {
+        self.check_file(&self.path(root))
+    }
+
+    //Same as check, for an answer that lives somewhere else (the reference solutions in solutions/)
+    pub fn check_file(&self, path: &Path) -> Status {
+        let source = match fs::read_to_string(path) {
+            Ok(source) => source,
+            Err(e) => return Status::DoesNotCompile(format!("could not read {}: {}", path.display(), e)),
+        };
+
+        //numbered, so checking the same exercise twice at once (tests do) doesn't share a directory
+        static CHECKS: AtomicUsize = AtomicUsize::new(0);
+        let build_dir = std::env::temp_dir().join(format!(
+            "bootcamp-exercise-{}-{}-{}",
+            std::process::id(),
+            self.id,
+            CHECKS.fetch_add(1, Ordering::Relaxed)
+        ));
+        let result = self.build_and_test(path, &build_dir);
+        let _ = fs::remove_dir_all(&build_dir);
+
+        match result {
+            Ok(()) => Status::Done,
+            Err(status) if source.contains("todo!()") => match status {
+                Status::DoesNotCompile(_) => status,
+                _ => Status::NotStarted,
+            },
+            Err(status) => status,
+        }
+    }
+
+    fn build_and_test(&self, path: &Path, build_dir: &Path) -> Result<(), Status> {
+        let to_status = |e: std::io::Error| Status::DoesNotCompile(e.to_string());
+        fs::create_dir_all(build_dir).map_err(to_status)?;
+
+        //include! pastes the assignment in as if it was written above the tests
+        let absolute = fs::canonicalize(path).map_err(to_status)?;
+        let harness = format!("include!({:?});\n\n{}", absolute, self.hidden_tests);
+        let harness_path = build_dir.join("check.rs");
+        fs::write(&harness_path, harness).map_err(to_status)?;
+
+        let binary = build_dir.join("check");
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+        let build = Command::new(rustc)
+            .args(["--edition", "2024", "--test", "-O", "-C", "overflow-checks=on", "-A", "warnings"])
+            .arg("-o")
+            .arg(&binary)
+            .arg(&harness_path)
+            .output()
+            .map_err(to_status)?;
+        if !build.status.success() {
+            return Err(Status::DoesNotCompile(String::from_utf8_lossy(&build.stderr).to_string()));
+        }
+
+        let run = Command::new(&binary)
+            .arg("--quiet")
+            .env("RUST_BACKTRACE", "0") //the assertion message is what matters, not the stack
+            .output()
+            .map_err(to_status)?;
+        if !run.status.success() {
+            return Err(Status::Failing(String::from_utf8_lossy(&run.stdout).to_string()));
+        }
+        Ok(())
+    }
+}
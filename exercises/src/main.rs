@@ -0,0 +1,94 @@
+use bootcamp_core::output::Table;
+use bootcamp_core::{BootcampError, Result, input};
+use exercises::exercise::{EXERCISES, Exercise, Status};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const USAGE: &str = "exercises [list] | exercises check [<exercise>] | exercises watch";
+
+fn main() {
+    if let Err(e) = start(&input::args()) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn start(args: &[String]) -> Result<()> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    match args.iter().map(|a| a.as_str()).collect::<Vec<_>>().as_slice() {
+        [] | ["list"] => {
+            let statuses: Vec<Status> = EXERCISES.iter().map(|e| e.check(&root)).collect();
+            print_progress(&statuses);
+            Ok(())
+        }
+        ["check"] => {
+            for exercise in &EXERCISES {
+                print_check(exercise, &exercise.check(&root), &root);
+            }
+            Ok(())
+        }
+        ["check", id] => {
+            let exercise = EXERCISES
+                .iter()
+                .find(|e| e.id == *id)
+                .ok_or_else(|| BootcampError::Usage(format!("no exercise called {:?}", id)))?;
+            print_check(exercise, &exercise.check(&root), &root);
+            Ok(())
+        }
+        ["watch"] => watch(&root),
+        _ => Err(BootcampError::Usage(String::from(USAGE))),
+    }
+}
+
+fn print_check(exercise: &Exercise, status: &Status, root: &Path) {
+    println!("{} ({}): {}", exercise.id, exercise.lesson, status.label());
+    if let Status::NotStarted = status {
+        println!("  replace the todo!() in {}", exercise.path(root).display());
+    }
+    if let Some(details) = status.details() {
+        println!("{}", details);
+    }
+}
+
+//One row per exercise grouped by lesson, followed by the overall progress
+fn print_progress(statuses: &[Status]) {
+    let mut table = Table::new(&["Lesson", "Exercise", "Status"]);
+    for (exercise, status) in EXERCISES.iter().zip(statuses) {
+        table.row(&[exercise.lesson, exercise.id, status.label()]);
+    }
+    table.write_to(&mut std::io::stdout()).unwrap();
+
+    let done = statuses.iter().filter(|s| matches!(s, Status::Done)).count();
+    println!("Progress: {}/{} done ({}%)", done, statuses.len(), done * 100 / statuses.len());
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+//Re-checks an exercise every time its file is saved. Polling the modification times keeps
+//this dependency free, twice a second is plenty for a human editing files.
+fn watch(root: &Path) -> Result<()> {
+    let mut statuses: Vec<Status> = EXERCISES.iter().map(|e| e.check(root)).collect();
+    print_progress(&statuses);
+    let mut seen: HashMap<&str, Option<SystemTime>> =
+        EXERCISES.iter().map(|e| (e.id, modified(&e.path(root)))).collect();
+    println!("Watching {} for changes, Ctrl+C to stop", root.join("assignments").display());
+
+    loop {
+        thread::sleep(Duration::from_millis(500));
+        for (i, exercise) in EXERCISES.iter().enumerate() {
+            let now = modified(&exercise.path(root));
+            if seen.insert(exercise.id, now) == Some(now) {
+                continue;
+            }
+            println!();
+            statuses[i] = exercise.check(root);
+            print_check(exercise, &statuses[i], root);
+            print_progress(&statuses);
+        }
+    }
+}
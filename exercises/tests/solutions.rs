@@ -0,0 +1,66 @@
+//Every check compiles with rustc, so these take a few seconds
+use exercises::exercise::{EXERCISES, Exercise, Status};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn exercise(id: &str) -> &'static Exercise {
+    EXERCISES.iter().find(|e| e.id == id).unwrap()
+}
+
+//Writes an answer to its own file, removed again when dropped
+struct Answer(PathBuf);
+
+impl Answer {
+    fn new(name: &str, source: &str) -> Answer {
+        let path = std::env::temp_dir().join(format!("exercises_{}_{}.rs", name, std::process::id()));
+        fs::write(&path, source).unwrap();
+        Answer(path)
+    }
+}
+
+impl Drop for Answer {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn reference_solutions_pass_the_hidden_tests() {
+    for exercise in &EXERCISES {
+        let status = exercise.check_file(&root().join("solutions").join(format!("{}.rs", exercise.id)));
+        assert!(matches!(status, Status::Done), "{}: {} {:?}", exercise.id, status.label(), status.details());
+    }
+}
+
+#[test]
+fn shipped_stubs_are_not_started() {
+    for exercise in &EXERCISES {
+        //assignments/ is where the answers go, so only the untouched stubs can be checked
+        let source = fs::read_to_string(exercise.path(root())).unwrap();
+        if !source.contains("todo!()") {
+            continue;
+        }
+        let status = exercise.check(root());
+        assert!(matches!(status, Status::NotStarted), "{}: {} {:?}", exercise.id, status.label(), status.details());
+    }
+}
+
+#[test]
+fn wrong_answers_fail() {
+    let answer = Answer::new("wrong", "pub fn filter_even(v: Vec<i32>) -> Vec<i32> {\n    v\n}\n");
+    let status = exercise("12_filter_even").check_file(&answer.0);
+    assert!(matches!(status, Status::Failing(_)), "{}", status.label());
+    assert!(status.details().unwrap().contains("keeps_only_even_numbers_in_order"));
+}
+
+#[test]
+fn answers_that_do_not_compile_say_so() {
+    let answer = Answer::new("broken", "pub fn odd_doubled(v: Vec<i32>) -> Vec<i32> {\n    v.len()\n}\n");
+    let status = exercise("13_odd_doubled").check_file(&answer.0);
+    assert!(matches!(status, Status::DoesNotCompile(_)), "{}", status.label());
+    assert!(status.details().unwrap().contains("mismatched types"));
+}
@@ -0,0 +1,229 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub struct Card {
+    pub id: String, //stable hash of where the card comes from, used as the key in the state file
+    pub source: String,
+    pub question: String,
+    pub answer: String,
+}
+
+//A markdown file cut into the pieces we build cards from
+enum Block {
+    Heading(usize, String),
+    Paragraph(Vec<String>),
+    Code(Vec<String>),
+}
+
+//FNV-1a: unlike std's DefaultHasher it gives the same ids on every Rust version
+fn fnv1a(text: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in text.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+//```inline``` code in the notes reads better as `inline`
+fn tidy(text: &str) -> String {
+    text.replace("```", "`").trim().to_string()
+}
+
+fn is_fence(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("```") && !trimmed[3..].contains("```")
+}
+
+fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<String> = Vec::new();
+    let mut code: Option<Vec<String>> = None;
+
+    for line in markdown.lines() {
+        if let Some(lines) = code.as_mut() {
+            if is_fence(line) {
+                blocks.push(Block::Code(code.take().unwrap()));
+            } else {
+                lines.push(line.to_string());
+            }
+            continue;
+        }
+
+        let level = line.chars().take_while(|c| *c == '#').count();
+        let is_heading = level > 0 && line[level..].starts_with(' ');
+        let ends_paragraph = is_fence(line) || is_heading || line.trim().is_empty();
+        if ends_paragraph && !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(std::mem::take(&mut paragraph)));
+        }
+        if is_fence(line) {
+            code = Some(Vec::new());
+        } else if is_heading {
+            blocks.push(Block::Heading(level, tidy(&line[level..])));
+        } else if !line.trim().is_empty() {
+            paragraph.push(line.trim().to_string());
+        }
+    }
+    if !paragraph.is_empty() {
+        blocks.push(Block::Paragraph(paragraph));
+    }
+    blocks
+}
+
+//Comments that mark a compiler error in the notes: `//ERR: ...`, `// Error: ...`, `//Error- ...`
+fn error_comment(line: &str) -> Option<String> {
+    let (_, comment) = line.split_once("//")?;
+    let comment = comment.trim();
+    let lower = comment.to_lowercase();
+    if lower.starts_with("err") {
+        let text = comment.trim_start_matches(|c: char| c.is_alphabetic()).trim_start_matches([':', '-', ' ']);
+        return Some(tidy(text));
+    }
+    None
+}
+
+//`NOTE: ...` and `**Note:** ...` passages
+fn note(line: &str) -> Option<String> {
+    let plain = line.replace("**", "");
+    let lower = plain.to_lowercase();
+    if lower.starts_with("note:") {
+        return Some(tidy(&plain[5..]));
+    }
+    None
+}
+
+//`- **Stack**: Fast allocation...` -> ("Stack", "Fast allocation...")
+fn bold_term(line: &str) -> Option<(String, String)> {
+    let line = line.trim_start_matches(|c: char| c == '-' || c == '.' || c.is_ascii_digit() || c == ' ');
+    let rest = line.strip_prefix("**")?;
+    let (term, definition) = rest.split_once("**")?;
+    let definition = definition.trim_start_matches(':').trim();
+    Some((term.trim_end_matches(':').trim().to_string(), tidy(definition)))
+}
+
+struct Builder<'a> {
+    file: &'a str,
+    cards: Vec<Card>,
+}
+
+impl Builder<'_> {
+    fn add(&mut self, source: &str, question: String, answer: String) {
+        if question.is_empty() || answer.is_empty() {
+            return;
+        }
+        self.cards.push(Card {
+            id: fnv1a(&format!("{}\n{}\n{}", self.file, source, question)),
+            source: format!("{} > {}", self.file, source),
+            question,
+            answer,
+        });
+    }
+}
+
+//Turns one NOTES file into cards:
+//- every heading with prose under it: "Explain: <heading>" -> the first paragraphs
+//- every code block with an error comment: "What goes wrong?" -> the error
+//- every **bold term**: definition, and every bold question -> the paragraph after it
+//- every > quoted passage: "Which topic?" -> the heading it belongs to
+pub fn cards_from_markdown(file: &str, markdown: &str) -> Vec<Card> {
+    let blocks = parse_blocks(markdown);
+    let mut builder = Builder { file, cards: Vec::new() };
+    let mut path: Vec<(usize, String)> = Vec::new();
+
+    for (i, block) in blocks.iter().enumerate() {
+        let heading = path.last().map_or(String::from(file), |(_, h)| h.clone());
+        let source = path.iter().map(|(_, h)| h.as_str()).collect::<Vec<_>>().join(" > ");
+        match block {
+            Block::Heading(level, text) => {
+                path.retain(|(l, _)| l < level);
+                path.push((*level, text.clone()));
+                let source = path.iter().map(|(_, h)| h.as_str()).collect::<Vec<_>>().join(" > ");
+
+                //prose up to the next heading, skipping code, at most two paragraphs
+                let prose: Vec<String> = blocks[i + 1..]
+                    .iter()
+                    .take_while(|b| !matches!(b, Block::Heading(..)))
+                    .filter_map(|b| match b {
+                        Block::Paragraph(lines) if !lines[0].starts_with('>') => Some(tidy(&lines.join("\n"))),
+                        _ => None,
+                    })
+                    .take(2)
+                    .collect();
+                let answer = prose.join("\n\n");
+                if answer.len() >= 40 {
+                    //headings like "Fix?" or "Which to use?" are questions already
+                    let question = match path.len() {
+                        2.. if text.ends_with('?') => format!("{} ({})", text, path[path.len() - 2].1),
+                        _ => format!("Explain: {}", text),
+                    };
+                    builder.add(&source, question, answer);
+                }
+            }
+            Block::Code(lines) => {
+                let mut errors: Vec<String> = lines.iter().filter_map(|l| error_comment(l)).collect();
+                //the notes sometimes put the error as a comment right after the code block
+                if let Some(Block::Paragraph(next)) = blocks.get(i + 1) {
+                    errors.extend(next.iter().filter(|l| l.starts_with("//")).filter_map(|l| error_comment(l)));
+                }
+                if errors.is_empty() {
+                    continue;
+                }
+                let code: Vec<&str> = lines
+                    .iter()
+                    .map(|l| match l.find("//") {
+                        Some(at) if error_comment(l).is_some() => l[..at].trim_end(),
+                        _ => l.as_str(),
+                    })
+                    .collect();
+                builder.add(
+                    &source,
+                    format!("What goes wrong in this code from \"{}\"?\n\n{}", heading, code.join("\n").trim_end()),
+                    errors.join("\n"),
+                );
+            }
+            Block::Paragraph(lines) => {
+                for line in lines {
+                    if let Some(quote) = line.strip_prefix('>') {
+                        let question = format!("Which topic is this about?\n\n\"{}\"", tidy(quote));
+                        builder.add(&source, question, heading.clone());
+                    } else if let Some(note) = note(line) {
+                        builder.add(&source, format!("What is the note in \"{}\"?", heading), note);
+                    } else if let Some((term, definition)) = bold_term(line) {
+                        if !definition.is_empty() && term.len() <= 60 {
+                            let question = if term.ends_with('?') {
+                                format!("{} ({})", term, heading)
+                            } else {
+                                format!("What is {}? ({})", term, heading)
+                            };
+                            builder.add(&source, question, definition);
+                        } else if definition.is_empty() && lines.len() == 1 && term.len() <= 120 {
+                            //a bold line on its own is a question, the next paragraph answers it
+                            if let Some(Block::Paragraph(answer)) = blocks.get(i + 1) {
+                                let question = format!("{}?", term.trim_end_matches('?'));
+                                builder.add(&source, question, tidy(&answer.join("\n")));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    builder.cards
+}
+
+//Cards from every NOTES*.md in `dir`, in file order
+pub fn load(dir: &Path) -> io::Result<Vec<Card>> {
+    let mut files: Vec<String> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name.starts_with("NOTES") && name.ends_with(".md"))
+        .collect();
+    files.sort();
+
+    let mut cards = Vec::new();
+    for file in files {
+        let markdown = fs::read_to_string(dir.join(&file))?;
+        cards.extend(cards_from_markdown(&file, &markdown));
+    }
+    Ok(cards)
+}
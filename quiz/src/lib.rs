@@ -0,0 +1,3 @@
+//The quiz binary's building blocks, a library so tests/ can reach them
+pub mod cards;
+pub mod schedule;
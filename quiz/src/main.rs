@@ -0,0 +1,153 @@
+use bootcamp_core::output::Table;
+use bootcamp_core::{BootcampError, Result, input};
+use quiz::cards::{self, Card};
+use quiz::schedule::{self, Grade, Review, State};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+const USAGE: &str = "quiz [start] [--new <count>] [--state <file>] | quiz stats | quiz cards";
+
+struct Options {
+    command: String,
+    new_cards: usize,
+    state: PathBuf,
+}
+
+fn main() {
+    if let Err(e) = start(&input::args()) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn parse_options(args: &[String], root: &Path) -> Result<Options> {
+    let mut options = Options {
+        command: String::from("start"),
+        new_cards: 10,
+        state: root.join(".quiz-state.tsv"),
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--new" => {
+                let value = args.next().ok_or_else(|| BootcampError::Usage(String::from(USAGE)))?;
+                options.new_cards = input::parse(value)?;
+            }
+            "--state" => {
+                let value = args.next().ok_or_else(|| BootcampError::Usage(String::from(USAGE)))?;
+                options.state = PathBuf::from(value);
+            }
+            "start" | "stats" | "cards" => options.command = arg.clone(),
+            _ => return Err(BootcampError::Usage(String::from(USAGE))),
+        }
+    }
+    Ok(options)
+}
+
+fn start(args: &[String]) -> Result<()> {
+    //the NOTES files live in the repo root, next to this crate's folder
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let options = parse_options(args, &root)?;
+    let cards = cards::load(&root)?;
+    let mut state = State::load(&options.state)?;
+
+    match options.command.as_str() {
+        "cards" => {
+            for card in &cards {
+                println!("[{}] {}\n{}\n", card.id, card.source, card.question);
+            }
+            println!("{} cards", cards.len());
+            Ok(())
+        }
+        "stats" => {
+            print_stats(&cards, &state);
+            Ok(())
+        }
+        _ => quiz(&cards, &mut state, &options),
+    }
+}
+
+fn print_stats(cards: &[Card], state: &State) {
+    let today = schedule::today();
+    let mut new = 0;
+    let mut due = 0;
+    let mut learning = 0;
+    let mut learned = 0;
+    for card in cards {
+        match state.reviews.get(&card.id) {
+            None => new += 1,
+            Some(r) if r.due <= today => due += 1,
+            Some(r) if r.interval < 21 => learning += 1,
+            Some(_) => learned += 1,
+        }
+    }
+    let mut table = Table::new(&["Cards", "New", "Due today", "Learning", "Learned (21+ days)"]);
+    table.row(&[cards.len(), new, due, learning, learned]);
+    table.write_to(&mut std::io::stdout()).unwrap();
+}
+
+fn ask_grade() -> Result<Option<Grade>> {
+    loop {
+        let answer = input::prompt("How well did you know it? [a]gain [h]ard [g]ood [e]asy [q]uit: ")?;
+        let Some(answer) = answer else {
+            return Ok(None); //end of input counts as quit
+        };
+        match answer.trim() {
+            "a" => return Ok(Some(Grade::Again)),
+            "h" => return Ok(Some(Grade::Hard)),
+            "g" => return Ok(Some(Grade::Good)),
+            "e" => return Ok(Some(Grade::Easy)),
+            "q" => return Ok(None),
+            _ => println!("Please type a, h, g, e or q"),
+        }
+    }
+}
+
+//Due cards first (most overdue first), then up to `new_cards` cards never seen before.
+//Forgotten cards come back at the end of the same session.
+fn quiz(cards: &[Card], state: &mut State, options: &Options) -> Result<()> {
+    let today = schedule::today();
+    let mut due: Vec<&Card> = cards
+        .iter()
+        .filter(|c| state.reviews.get(&c.id).is_some_and(|r| r.due <= today))
+        .collect();
+    due.sort_by_key(|c| state.reviews[&c.id].due);
+    let new = cards
+        .iter()
+        .filter(|c| !state.reviews.contains_key(&c.id))
+        .take(options.new_cards);
+    let mut queue: VecDeque<&Card> = due.into_iter().chain(new).collect();
+
+    if queue.is_empty() {
+        println!("Nothing to review today, come back tomorrow!");
+        return Ok(());
+    }
+
+    let mut reviewed = 0;
+    while let Some(card) = queue.pop_front() {
+        println!("\n--- {} ({} left) ---", card.source, queue.len() + 1);
+        println!("{}\n", card.question);
+        if input::prompt("Press Enter to show the answer")?.is_none() {
+            break;
+        }
+        println!("\n{}\n", card.answer);
+
+        let Some(grade) = ask_grade()? else {
+            break;
+        };
+        state
+            .reviews
+            .entry(card.id.clone())
+            .or_insert_with(|| Review::new(today))
+            .grade(grade, today);
+        state.save(&options.state)?; //save after every answer so quitting loses nothing
+        reviewed += 1;
+
+        if grade == Grade::Again {
+            queue.push_back(card);
+        }
+    }
+
+    println!("\nReviewed {} card(s), progress saved to {}", reviewed, options.state.display());
+    Ok(())
+}
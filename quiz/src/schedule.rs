@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+//How well the answer was remembered, picked by the user after seeing it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Grade {
+    Again, //forgot it
+    Hard,
+    Good,
+    Easy,
+}
+
+impl Grade {
+    //SM-2 rates answers from 0 to 5, anything below 3 counts as forgotten
+    fn quality(self) -> f64 {
+        match self {
+            Grade::Again => 1.0,
+            Grade::Hard => 3.0,
+            Grade::Good => 4.0,
+            Grade::Easy => 5.0,
+        }
+    }
+}
+
+//Review state of one card, as in the SM-2 algorithm used by SuperMemo and Anki
+#[derive(Debug, Clone, PartialEq)]
+pub struct Review {
+    pub repetitions: u32, //correct answers in a row
+    pub interval: u32,    //days until the next review
+    pub ease: f64,        //how fast the interval grows
+    pub due: u64,         //day number (days since 1970-01-01)
+}
+
+impl Review {
+    pub fn new(today: u64) -> Review {
+        Review {
+            repetitions: 0,
+            interval: 0,
+            ease: 2.5,
+            due: today,
+        }
+    }
+
+    pub fn grade(&mut self, grade: Grade, today: u64) {
+        let q = grade.quality();
+        if q < 3.0 {
+            //start over, but keep the ease so hard cards stay hard
+            self.repetitions = 0;
+            self.interval = 1;
+        } else {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (self.interval as f64 * self.ease).round() as u32,
+            };
+            self.repetitions += 1;
+        }
+        self.ease = (self.ease + 0.1 - (5.0 - q) * (0.08 + (5.0 - q) * 0.02)).max(1.3);
+        self.due = today + self.interval as u64;
+    }
+}
+
+pub fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() / 86_400)
+}
+
+//Reviews by card id, saved as one tab separated line per card:
+//`<id>\t<repetitions>\t<interval>\t<ease>\t<due>`
+pub struct State {
+    pub reviews: HashMap<String, Review>,
+}
+
+impl State {
+    //A missing file is a fresh start, broken lines are skipped
+    pub fn load(path: &Path) -> io::Result<State> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let reviews = text.lines().filter_map(parse_line).collect();
+        Ok(State { reviews })
+    }
+
+    //Writes to a temporary file first so a crash can't leave half a state file behind
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut ids: Vec<&String> = self.reviews.keys().collect();
+        ids.sort();
+
+        let tmp = path.with_extension("tmp");
+        let mut file = fs::File::create(&tmp)?;
+        for id in ids {
+            let r = &self.reviews[id];
+            writeln!(file, "{}\t{}\t{}\t{:.2}\t{}", id, r.repetitions, r.interval, r.ease, r.due)?;
+        }
+        file.sync_all()?;
+        fs::rename(tmp, path)
+    }
+}
+
+fn parse_line(line: &str) -> Option<(String, Review)> {
+    let fields: Vec<&str> = line.split('\t').collect();
+    let [id, repetitions, interval, ease, due] = fields.as_slice() else {
+        return None;
+    };
+    let review = Review {
+        repetitions: repetitions.parse().ok()?,
+        interval: interval.parse().ok()?,
+        ease: ease.parse().ok()?,
+        due: due.parse().ok()?,
+    };
+    Some((id.to_string(), review))
+}
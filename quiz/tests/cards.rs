@@ -0,0 +1,121 @@
+use quiz::cards::{cards_from_markdown, Card};
+
+const NOTES: &str = r#"# Ownership
+
+Every value has a single owner, and the value is dropped when the owner goes out of scope.
+
+## Moves
+
+```rust
+let s1 = String::from("hi");
+let s2 = s1;
+println!("{}", s1); //ERR: borrow of moved value: `s1`
+```
+
+- **Move**: ownership passes to the new variable
+
+> Ownership rules are checked at compile time.
+
+NOTE: Copy types are copied instead of moved.
+"#;
+
+fn questions(cards: &[Card]) -> Vec<&str> {
+    cards.iter().map(|c| c.question.as_str()).collect()
+}
+
+fn find<'a>(cards: &'a [Card], question: &str) -> &'a Card {
+    cards
+        .iter()
+        .find(|c| c.question == question)
+        .unwrap_or_else(|| panic!("no card {:?} in {:#?}", question, questions(cards)))
+}
+
+#[test]
+fn every_kind_of_card() {
+    let cards = cards_from_markdown("NOTES.md", NOTES);
+    assert_eq!(
+        questions(&cards),
+        [
+            "Explain: Ownership",
+            "Explain: Moves",
+            "What goes wrong in this code from \"Moves\"?\n\nlet s1 = String::from(\"hi\");\nlet s2 = s1;\nprintln!(\"{}\", s1);",
+            "What is Move? (Moves)",
+            "Which topic is this about?\n\n\"Ownership rules are checked at compile time.\"",
+            "What is the note in \"Moves\"?",
+        ]
+    );
+    let answers: Vec<&str> = cards.iter().map(|c| c.answer.as_str()).collect();
+    assert_eq!(
+        answers,
+        [
+            "Every value has a single owner, and the value is dropped when the owner goes out of scope.",
+            "- **Move**: ownership passes to the new variable\n\nNOTE: Copy types are copied instead of moved.",
+            "borrow of moved value: `s1`",
+            "ownership passes to the new variable",
+            "Moves",
+            "Copy types are copied instead of moved.",
+        ]
+    );
+    assert_eq!(cards[1].source, "NOTES.md > Ownership > Moves");
+}
+
+#[test]
+fn headings_need_enough_prose() {
+    let cards = cards_from_markdown("NOTES.md", "# Short\n\nToo short to ask about.\n\n# Code only\n\n```\nlet x = 5;\n```\n");
+    assert!(cards.is_empty(), "{:?}", questions(&cards));
+}
+
+#[test]
+fn question_headings_name_their_parent() {
+    let markdown = "# Borrowing\n\n## Fix?\n\nClone the value, or borrow it with & so the owner keeps it.\n";
+    let cards = cards_from_markdown("NOTES2.md", markdown);
+    assert_eq!(questions(&cards), ["Fix? (Borrowing)"]);
+}
+
+#[test]
+fn bold_lines_on_their_own_are_questions() {
+    let markdown = "# Lifetimes\n\n**Why does Rust need lifetimes**\n\nSo references never outlive the data they point to.\n";
+    let cards = cards_from_markdown("NOTES.md", markdown);
+    let card = find(&cards, "Why does Rust need lifetimes?");
+    assert_eq!(card.answer, "So references never outlive the data they point to.");
+    assert_eq!(card.source, "NOTES.md > Lifetimes");
+}
+
+#[test]
+fn error_comments_in_any_spelling() {
+    for comment in ["//ERR: use of moved value", "// Error: use of moved value", "//Error- use of moved value"] {
+        let markdown = format!("# Moves\n\n```\nlet b = a;\nlet c = a; {}\n```\n", comment);
+        let cards = cards_from_markdown("NOTES.md", &markdown);
+        let card = find(&cards, "What goes wrong in this code from \"Moves\"?\n\nlet b = a;\nlet c = a;");
+        assert_eq!(card.answer, "use of moved value");
+    }
+
+    //a comment right after the block counts too
+    let markdown = "# Moves\n\n```\nlet c = a;\n```\n//ERR: value used after move\n";
+    let cards = cards_from_markdown("NOTES.md", markdown);
+    assert_eq!(find(&cards, "What goes wrong in this code from \"Moves\"?\n\nlet c = a;").answer, "value used after move");
+}
+
+#[test]
+fn fenced_code_does_not_end_at_inline_backticks() {
+    //```inline``` on one line is inline code, not a fence, and reads as `inline` in the answer
+    let markdown = "# Slices\n\nA ```&str``` is a view into a String, it never owns the text itself.\n";
+    let cards = cards_from_markdown("NOTES.md", markdown);
+    assert_eq!(cards[0].answer, "A `&str` is a view into a String, it never owns the text itself.");
+}
+
+#[test]
+fn ids_are_stable_and_unique() {
+    let first = cards_from_markdown("NOTES.md", NOTES);
+    let again = cards_from_markdown("NOTES.md", NOTES);
+    let elsewhere = cards_from_markdown("NOTES2.md", NOTES);
+    for ((a, b), c) in first.iter().zip(&again).zip(&elsewhere) {
+        assert_eq!(a.id, b.id);
+        assert_ne!(a.id, c.id);
+        assert_eq!(a.id.len(), 16);
+    }
+    let mut ids: Vec<&str> = first.iter().map(|c| c.id.as_str()).collect();
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), first.len());
+}
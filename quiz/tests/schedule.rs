@@ -0,0 +1,109 @@
+use quiz::schedule::{Grade, Review, State};
+use std::collections::HashMap;
+
+const TODAY: u64 = 20_000;
+
+fn assert_ease(review: &Review, expected: f64) {
+    assert!((review.ease - expected).abs() < 1e-9, "ease {} instead of {}", review.ease, expected);
+}
+
+#[test]
+fn new_cards_are_due_today() {
+    let review = Review::new(TODAY);
+    assert_eq!((review.repetitions, review.interval, review.due), (0, 0, TODAY));
+    assert_ease(&review, 2.5);
+}
+
+#[test]
+fn passing_grows_the_interval() {
+    let mut review = Review::new(TODAY);
+    review.grade(Grade::Good, TODAY);
+    assert_eq!((review.repetitions, review.interval, review.due), (1, 1, TODAY + 1));
+    assert_ease(&review, 2.5); //Good leaves the ease alone
+
+    review.grade(Grade::Good, TODAY + 1);
+    assert_eq!((review.repetitions, review.interval, review.due), (2, 6, TODAY + 7));
+
+    //from the third pass on: interval * ease
+    review.grade(Grade::Good, TODAY + 7);
+    assert_eq!((review.repetitions, review.interval, review.due), (3, 15, TODAY + 22));
+    review.grade(Grade::Good, TODAY + 22);
+    assert_eq!(review.interval, 38); //15 * 2.5 = 37.5, rounded
+}
+
+#[test]
+fn grades_change_the_ease() {
+    let graded = |grade| {
+        let mut review = Review::new(TODAY);
+        review.grade(grade, TODAY);
+        review
+    };
+    //ease + 0.1 - (5 - q) * (0.08 + (5 - q) * 0.02)
+    assert_ease(&graded(Grade::Easy), 2.6);
+    assert_ease(&graded(Grade::Good), 2.5);
+    assert_ease(&graded(Grade::Hard), 2.36);
+    assert_ease(&graded(Grade::Again), 1.96);
+    assert_eq!(graded(Grade::Hard).repetitions, 1); //hard still counts as remembered
+}
+
+#[test]
+fn failing_starts_over_but_keeps_the_ease() {
+    let mut review = Review::new(TODAY);
+    for day in 0..3 {
+        review.grade(Grade::Easy, TODAY + day);
+    }
+    assert_eq!(review.repetitions, 3);
+    assert_ease(&review, 2.8);
+
+    review.grade(Grade::Again, TODAY + 10);
+    assert_eq!((review.repetitions, review.interval, review.due), (0, 1, TODAY + 11));
+    assert_ease(&review, 2.26);
+
+    review.grade(Grade::Good, TODAY + 11);
+    assert_eq!(review.interval, 1); //back to the first step
+}
+
+#[test]
+fn ease_never_drops_below_the_minimum() {
+    let mut review = Review::new(TODAY);
+    for _ in 0..10 {
+        review.grade(Grade::Again, TODAY);
+    }
+    assert_ease(&review, 1.3);
+    review.grade(Grade::Hard, TODAY);
+    assert_ease(&review, 1.3);
+    review.grade(Grade::Easy, TODAY);
+    assert_ease(&review, 1.4);
+}
+
+#[test]
+fn state_round_trip() {
+    let path = std::env::temp_dir().join(format!("quiz_state_{}.tsv", std::process::id()));
+    let mut reviews = HashMap::new();
+    let mut review = Review::new(TODAY);
+    review.grade(Grade::Hard, TODAY);
+    reviews.insert(String::from("00000000000000aa"), review);
+    reviews.insert(String::from("00000000000000bb"), Review::new(TODAY));
+    State { reviews: reviews.clone() }.save(&path).unwrap();
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    let loaded = State::load(&path);
+    std::fs::write(&path, "broken line\n00000000000000cc\t1\t1\tx\t5\n00000000000000dd\t1\t6\t2.50\t7\n").unwrap();
+    let partly_broken = State::load(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(text, "00000000000000aa\t1\t1\t2.36\t20001\n00000000000000bb\t0\t0\t2.50\t20000\n");
+    //the ease is saved with two decimals, everything else exactly
+    let loaded = loaded.unwrap();
+    assert_eq!(loaded.reviews.len(), 2);
+    for (id, review) in &reviews {
+        let back = &loaded.reviews[id];
+        assert_eq!((back.repetitions, back.interval, back.due), (review.repetitions, review.interval, review.due));
+        assert!((back.ease - review.ease).abs() < 0.005);
+    }
+    let partly_broken = partly_broken.unwrap();
+    assert_eq!(partly_broken.reviews.len(), 1);
+    assert_eq!(partly_broken.reviews["00000000000000dd"].interval, 6);
+
+    assert!(State::load(&path).unwrap().reviews.is_empty()); //missing file: nothing reviewed yet
+}